@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Group<E> {
+    /// Enforces that the group element is in the prime-order subgroup.
+    ///
+    /// This is achieved by witnessing the group element divided by the cofactor,
+    /// and enforcing that multiplying the witness by the cofactor yields `self`.
+    /// As every multiple of the cofactor lies in the prime-order subgroup, this
+    /// is satisfiable if and only if `self` is in the prime-order subgroup.
+    pub fn enforce_in_subgroup(&self) {
+        // Determine the mode of the witness.
+        let mode = match self.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        };
+
+        // Witness the group element divided by the cofactor.
+        let quotient = Group::<E>::new(mode, self.eject_value().div_by_cofactor());
+
+        // Ensure `quotient * cofactor == self`.
+        let candidate = quotient.mul_by_cofactor();
+        E::assert_eq(&candidate.x, &self.x);
+        E::assert_eq(&candidate.y, &self.y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_enforce_in_subgroup(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let point: console::Group<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+
+            // Initialize the input.
+            let affine = Group::<Circuit>::new(mode, point);
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                affine.enforce_in_subgroup();
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_enforce_in_subgroup_constant() {
+        check_enforce_in_subgroup(Mode::Constant, 10, 0, 0, 0);
+    }
+
+    /// The cost of a non-constant group element is 3 constraints to witness the quotient on the curve,
+    /// 10 constraints to multiply it by the cofactor, and 2 constraints to check the result matches.
+    #[test]
+    fn test_enforce_in_subgroup_public() {
+        check_enforce_in_subgroup(Mode::Public, 4, 0, 14, 15);
+    }
+
+    #[test]
+    fn test_enforce_in_subgroup_private() {
+        check_enforce_in_subgroup(Mode::Private, 4, 0, 14, 15);
+    }
+
+    #[test]
+    fn test_enforce_in_subgroup_fails() {
+        // Initialize the point of order two, (0, -1), which is on the curve but not in the prime-order subgroup.
+        let x = Field::<Circuit>::new(Mode::Private, console::Field::zero());
+        let y = Field::<Circuit>::new(Mode::Private, -console::Field::one());
+        let affine = Group::<Circuit>::from_xy_coordinates(x, y);
+        assert!(Circuit::is_satisfied());
+
+        // Ensure the point is rejected.
+        affine.enforce_in_subgroup();
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -77,6 +77,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_from_bits_recovers_both_y_branches() {
+        let mut rng = TestRng::default();
+
+        // Sample points until both branches of the y-coordinate (y < -y, and y > -y) are covered.
+        let (mut num_lower, mut num_upper) = (0, 0);
+        while num_lower < ITERATIONS || num_upper < ITERATIONS {
+            let expected: console::Group<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let y = expected.to_y_coordinate();
+            match y < -y {
+                true => num_lower += 1,
+                false => num_upper += 1,
+            }
+
+            // Ensure the point is recovered exactly from the bits of its x-coordinate, as only one
+            // of (x, y) and (x, -y) is in the prime-order subgroup, so no sign bit is required.
+            let candidate = Group::<Circuit>::new(Mode::Private, expected);
+            assert_eq!(expected, Group::<Circuit>::from_bits_le(&candidate.to_bits_le()).eject_value());
+            assert_eq!(expected, Group::<Circuit>::from_bits_be(&candidate.to_bits_be()).eject_value());
+            Circuit::reset();
+        }
+    }
+
     #[test]
     fn test_from_bits_le_constant() {
         check_from_bits_le(Mode::Constant, 3, 0, 0, 0);
@@ -28,6 +28,17 @@ impl<E: Environment> Group<E> {
 
         Self::from_xy_coordinates(x, y)
     }
+
+    /// Initializes the two affine group elements with the given x-coordinate, `(x, y)` and `(x, -y)`,
+    /// where `(x, y)` is the element returned by `from_x_coordinate`. The two elements differ by the point
+    /// of order two, so callers may select between them with an explicit sign bit.
+    /// For safety, the resulting points are always enforced to be on the curve with constraints.
+    pub fn from_x_coordinate_both(x: Field<E>) -> (Self, Self) {
+        let point = Self::from_x_coordinate(x);
+        // The curve equation only depends on y^2, so the negated point requires no additional constraints.
+        let other = Self { x: point.x.clone(), y: -&point.y };
+        (point, other)
+    }
 }
 
 #[cfg(test)]
@@ -62,6 +73,71 @@ mod tests {
         }
     }
 
+    fn check_from_x_coordinate_both(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let point: console::Group<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+
+            // Inject the x-coordinate.
+            let x_coordinate = Field::new(mode, point.to_x_coordinate());
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let (first, second) = Group::<Circuit>::from_x_coordinate_both(x_coordinate);
+                assert_eq!(point, first.eject_value());
+                // Ensure the points share the x-coordinate, and have negated y-coordinates.
+                assert_eq!(first.to_x_coordinate().eject_value(), second.to_x_coordinate().eject_value());
+                assert_eq!(-first.to_y_coordinate().eject_value(), second.to_y_coordinate().eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_x_coordinate_both_on_curve() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let point: console::Group<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let (first, second) =
+                Group::<Circuit>::from_x_coordinate_both(Field::new(Mode::Private, point.to_x_coordinate()));
+
+            // Ensure both points lie on the curve.
+            for candidate in [first, second] {
+                let (x, y) = (candidate.to_x_coordinate().eject_value(), candidate.to_y_coordinate().eject_value());
+                let (x2, y2) = (x.square(), y.square());
+                let a = console::Field::new(<Circuit as Environment>::EDWARDS_A);
+                let d = console::Field::new(<Circuit as Environment>::EDWARDS_D);
+                assert_eq!(a * x2 + y2, console::Field::one() + d * x2 * y2);
+            }
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_x_coordinate_both_constant() {
+        check_from_x_coordinate_both(Mode::Constant, 3, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_from_x_coordinate_both_public() {
+        check_from_x_coordinate_both(Mode::Public, 2, 0, 3, 3);
+    }
+
+    #[test]
+    fn test_from_x_coordinate_both_private() {
+        check_from_x_coordinate_both(Mode::Private, 2, 0, 3, 3);
+    }
+
     #[test]
     fn test_from_x_coordinate_constant() {
         check_from_x_coordinate(Mode::Constant, 3, 0, 0, 0);
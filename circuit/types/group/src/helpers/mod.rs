@@ -16,6 +16,7 @@
 
 use super::*;
 
+pub mod enforce_in_subgroup;
 pub mod from_bits;
 pub mod from_x_coordinate;
 pub mod from_xy_coordinates;
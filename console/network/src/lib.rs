@@ -73,6 +73,19 @@ pub trait Network:
     /// The network edition.
     const EDITION: u16;
 
+    /// The timestamp of the genesis block.
+    const GENESIS_TIMESTAMP: i64 = 0;
+    /// The anchor time per block in seconds, which is the targeted time between blocks.
+    const ANCHOR_TIME: u16 = 25;
+    /// The number of recent blocks used to compute the median block timestamp.
+    const NUM_MEDIAN_TIMESTAMP_BLOCKS: u32 = 11;
+    /// The maximum number of anchor times that a block timestamp may be ahead of the current time.
+    const MAX_FUTURE_TIMESTAMP_ANCHORS: u16 = 10;
+    /// The maximum size of a block in bytes.
+    const MAX_BLOCK_SIZE: usize = 128 * 1024 * 1024;
+    /// The minimum fee of a transition in gates, which is not enforced for the genesis block.
+    const MINIMUM_FEE: i64 = 0;
+
     /// The maximum recursive depth of a value and/or entry.
     /// Note: This value must be strictly less than u8::MAX.
     const MAX_DATA_DEPTH: usize = 32;
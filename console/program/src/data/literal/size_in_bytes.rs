@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Literal<N> {
+    /// Returns the number of bytes of this literal, as encoded by `to_bytes_le` (excluding the variant).
+    pub fn size_in_bytes(&self) -> u16 {
+        let size = match self {
+            Self::Address(..) => Address::<N>::size_in_bytes(),
+            Self::Boolean(..) => Boolean::<N>::size_in_bytes(),
+            Self::Field(..) => Field::<N>::size_in_bytes(),
+            Self::Group(..) => Group::<N>::size_in_bytes(),
+            Self::I8(..) => I8::<N>::size_in_bytes(),
+            Self::I16(..) => I16::<N>::size_in_bytes(),
+            Self::I32(..) => I32::<N>::size_in_bytes(),
+            Self::I64(..) => I64::<N>::size_in_bytes(),
+            Self::I128(..) => I128::<N>::size_in_bytes(),
+            Self::U8(..) => U8::<N>::size_in_bytes(),
+            Self::U16(..) => U16::<N>::size_in_bytes(),
+            Self::U32(..) => U32::<N>::size_in_bytes(),
+            Self::U64(..) => U64::<N>::size_in_bytes(),
+            Self::U128(..) => U128::<N>::size_in_bytes(),
+            Self::Scalar(..) => Scalar::<N>::size_in_bytes(),
+            // The string is encoded as a `u16` length, followed by its bytes.
+            Self::String(string) => match string.len().checked_add(2) {
+                Some(size) => size,
+                None => N::halt("String exceeds usize::MAX bytes."),
+            },
+        };
+        u16::try_from(size).or_halt_with::<N>("Literal exceeds u16::MAX bytes.")
+    }
+}
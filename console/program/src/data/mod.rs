@@ -24,7 +24,7 @@ mod literal;
 pub use literal::Literal;
 
 mod plaintext;
-pub use plaintext::Plaintext;
+pub use plaintext::{Plaintext, PlaintextReader};
 
 mod record;
 pub use record::{Balance, Entry, Owner, Record};
@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns `Ok(())` if `self` is an integer literal in the range `[min, max]`.
+    /// Returns an error if `self` is out of range, is not a literal, or does not match the type of `min` and `max`.
+    pub fn assert_in_range(&self, min: &Literal<N>, max: &Literal<N>) -> Result<()> {
+        // Retrieve the literal.
+        let literal = match self {
+            Self::Literal(literal, ..) => literal,
+            Self::Interface(..) => bail!("Expected an integer literal, found an interface"),
+        };
+
+        /// Ensures the given value is in the range `[min, max]`.
+        fn check_in_range<T: PartialOrd + Display>(value: &T, min: &T, max: &T) -> Result<()> {
+            match min <= value && value <= max {
+                true => Ok(()),
+                false => bail!("'{value}' is not in the range [{min}, {max}]"),
+            }
+        }
+
+        match (literal, min, max) {
+            (Literal::I8(value), Literal::I8(min), Literal::I8(max)) => check_in_range(value, min, max),
+            (Literal::I16(value), Literal::I16(min), Literal::I16(max)) => check_in_range(value, min, max),
+            (Literal::I32(value), Literal::I32(min), Literal::I32(max)) => check_in_range(value, min, max),
+            (Literal::I64(value), Literal::I64(min), Literal::I64(max)) => check_in_range(value, min, max),
+            (Literal::I128(value), Literal::I128(min), Literal::I128(max)) => check_in_range(value, min, max),
+            (Literal::U8(value), Literal::U8(min), Literal::U8(max)) => check_in_range(value, min, max),
+            (Literal::U16(value), Literal::U16(min), Literal::U16(max)) => check_in_range(value, min, max),
+            (Literal::U32(value), Literal::U32(min), Literal::U32(max)) => check_in_range(value, min, max),
+            (Literal::U64(value), Literal::U64(min), Literal::U64(max)) => check_in_range(value, min, max),
+            (Literal::U128(value), Literal::U128(min), Literal::U128(max)) => check_in_range(value, min, max),
+            _ => bail!(
+                "Expected integer literals of the same type, found '{}', '{}', and '{}'",
+                literal.to_type(),
+                min.to_type(),
+                max.to_type()
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_assert_in_range() -> Result<()> {
+        let min = Literal::<CurrentNetwork>::from_str("10u64")?;
+        let max = Literal::<CurrentNetwork>::from_str("20u64")?;
+
+        // Ensure values in range are accepted.
+        for value in ["10u64", "15u64", "20u64"] {
+            Plaintext::<CurrentNetwork>::from_str(value)?.assert_in_range(&min, &max)?;
+        }
+        // Ensure signed values in range are accepted.
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("-5i8")?;
+        plaintext.assert_in_range(&Literal::from_str("-10i8")?, &Literal::from_str("0i8")?)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_assert_in_range_fails() -> Result<()> {
+        let min = Literal::<CurrentNetwork>::from_str("10u64")?;
+        let max = Literal::<CurrentNetwork>::from_str("20u64")?;
+
+        // Ensure values out of range are rejected, with the actual value in the error.
+        for value in ["9u64", "21u64"] {
+            let error = Plaintext::<CurrentNetwork>::from_str(value)?.assert_in_range(&min, &max).unwrap_err();
+            assert!(error.to_string().contains(value));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_assert_in_range_type_mismatch() -> Result<()> {
+        let min = Literal::<CurrentNetwork>::from_str("10u64")?;
+        let max = Literal::<CurrentNetwork>::from_str("20u64")?;
+
+        // Ensure a value of a different integer type is rejected.
+        assert!(Plaintext::<CurrentNetwork>::from_str("15u32")?.assert_in_range(&min, &max).is_err());
+        // Ensure a non-integer literal is rejected.
+        assert!(Plaintext::<CurrentNetwork>::from_str("15field")?.assert_in_range(&min, &max).is_err());
+        // Ensure mismatched bounds are rejected.
+        let max = Literal::<CurrentNetwork>::from_str("20u32")?;
+        assert!(Plaintext::<CurrentNetwork>::from_str("15u64")?.assert_in_range(&min, &max).is_err());
+        // Ensure an interface is rejected.
+        assert!(Plaintext::<CurrentNetwork>::from_str("{ a: 15u64 }")?.assert_in_range(&min, &max).is_err());
+        Ok(())
+    }
+}
@@ -130,6 +130,23 @@ mod tests {
         assert!(!*candidate);
     }
 
+    #[test]
+    fn test_eq_ignores_cached_bits() {
+        // Sample two plaintexts from the same string.
+        let plaintext = sample_plaintext();
+        let candidate = sample_plaintext();
+
+        // Populate the cached bits of the candidate, including those of its nested members.
+        let bits = candidate.to_bits_le();
+        assert!(matches!(&candidate, Plaintext::Interface(_, cache) if cache.get().is_some()));
+
+        // Ensure the plaintexts are equal, regardless of the cached bits.
+        assert_eq!(plaintext, candidate);
+        assert_eq!(candidate, plaintext);
+        assert_eq!(plaintext.to_bits_le(), bits);
+        assert_ne!(sample_mismatched_plaintext(), candidate);
+    }
+
     #[test]
     fn test_is_equal() {
         check_is_equal()
@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod assert_in_range;
 mod bytes;
 mod encrypt;
 mod equal;
@@ -22,11 +23,15 @@ mod from_bits;
 mod from_fields;
 mod num_randomizers;
 mod parse;
+mod reader;
 mod serialize;
+mod size_in_bits;
 mod size_in_fields;
 mod to_bits;
 mod to_fields;
 
+pub use reader::PlaintextReader;
+
 use crate::{Ciphertext, Identifier, Literal};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
@@ -168,6 +168,31 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_signed_literal() -> Result<()> {
+        // Ensure signed literals round-trip, including the sign and the type suffix.
+        for string in
+            ["-5i8", "-5i16", "-5i32", "-5i64", "-5i128", "-128i8", "-170141183460469231731687303715884105728i128"]
+        {
+            let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse(string)?;
+            assert!(matches!(candidate, Plaintext::Literal(..)));
+            assert_eq!(string, candidate.to_string());
+            assert_eq!("", remainder);
+            assert_eq!(candidate, Plaintext::from_str(&candidate.to_string())?);
+        }
+
+        // Ensure a negative member of an interface round-trips.
+        let candidate = Plaintext::<CurrentNetwork>::from_str("{ fee: -5i64 }")?;
+        assert_eq!("{\n  fee: -5i64\n}", candidate.to_string());
+        assert_eq!(candidate, Plaintext::from_str(&candidate.to_string())?);
+
+        // Ensure a negative unsigned literal, and an out-of-range signed literal, are rejected.
+        assert!(Plaintext::<CurrentNetwork>::parse("-5u64").is_err());
+        assert!(Plaintext::<CurrentNetwork>::parse("-129i8").is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_parse_interface() -> Result<()> {
         // Sanity check.
@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A reader for extracting typed members from a plaintext interface.
+#[derive(Copy, Clone)]
+pub struct PlaintextReader<'a, N: Network> {
+    /// The members of the interface.
+    members: &'a IndexMap<Identifier<N>, Plaintext<N>>,
+}
+
+impl<N: Network> Plaintext<N> {
+    /// Returns a reader over the members of the plaintext interface.
+    pub fn reader(&self) -> Result<PlaintextReader<'_, N>> {
+        PlaintextReader::new(self)
+    }
+}
+
+/// Implements a reader method that extracts the given literal variant as the given type.
+macro_rules! impl_read_literal {
+    ($(($method:ident, $variant:ident, $output:ty, $type_name:literal, $convert:expr)),+) => {
+        $(
+            #[doc = concat!("Returns the member with the given name, as a `", $type_name, "`.")]
+            pub fn $method(&self, name: &str) -> Result<$output> {
+                match self.read_literal(name)? {
+                    Literal::$variant(value) => Ok($convert(value)),
+                    literal => bail!("Member '{name}' is not a {}, found '{literal}'", $type_name),
+                }
+            }
+        )+
+    };
+}
+
+impl<'a, N: Network> PlaintextReader<'a, N> {
+    impl_read_literal!(
+        (read_address, Address, Address<N>, "address", |value: &Address<N>| *value),
+        (read_boolean, Boolean, bool, "boolean", |value: &Boolean<N>| **value),
+        (read_field, Field, Field<N>, "field", |value: &Field<N>| *value),
+        (read_group, Group, Group<N>, "group", |value: &Group<N>| *value),
+        (read_scalar, Scalar, Scalar<N>, "scalar", |value: &Scalar<N>| *value),
+        (read_i8, I8, i8, "i8", |value: &I8<N>| **value),
+        (read_i16, I16, i16, "i16", |value: &I16<N>| **value),
+        (read_i32, I32, i32, "i32", |value: &I32<N>| **value),
+        (read_i64, I64, i64, "i64", |value: &I64<N>| **value),
+        (read_i128, I128, i128, "i128", |value: &I128<N>| **value),
+        (read_u8, U8, u8, "u8", |value: &U8<N>| **value),
+        (read_u16, U16, u16, "u16", |value: &U16<N>| **value),
+        (read_u32, U32, u32, "u32", |value: &U32<N>| **value),
+        (read_u64, U64, u64, "u64", |value: &U64<N>| **value),
+        (read_u128, U128, u128, "u128", |value: &U128<N>| **value),
+        (read_string, String, String, "string", |value: &StringType<N>| value.to_string())
+    );
+
+    /// Initializes a new reader over the members of the given plaintext interface.
+    pub fn new(plaintext: &'a Plaintext<N>) -> Result<Self> {
+        match plaintext {
+            Plaintext::Literal(..) => bail!("'{plaintext}' is not an interface"),
+            Plaintext::Interface(members, ..) => Ok(Self { members }),
+        }
+    }
+
+    /// Returns the member with the given name.
+    pub fn read(&self, name: &str) -> Result<&'a Plaintext<N>> {
+        let identifier = Identifier::from_str(name)?;
+        match self.members.get(&identifier) {
+            Some(member) => Ok(member),
+            None => bail!("Missing member '{name}' in the interface"),
+        }
+    }
+
+    /// Returns the member with the given name, as a literal.
+    pub fn read_literal(&self, name: &str) -> Result<&'a Literal<N>> {
+        match self.read(name)? {
+            Plaintext::Literal(literal, ..) => Ok(literal),
+            Plaintext::Interface(..) => bail!("Member '{name}' is not a literal, found an interface"),
+        }
+    }
+
+    /// Returns a reader over the members of the interface with the given name.
+    pub fn read_nested(&self, name: &str) -> Result<PlaintextReader<'a, N>> {
+        match self.read(name)? {
+            Plaintext::Literal(literal, ..) => bail!("Member '{name}' is not an interface, found '{literal}'"),
+            Plaintext::Interface(members, ..) => Ok(Self { members }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_reader() -> Result<()> {
+        let plaintext = Plaintext::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, amount: 100u64, active: true, data: { id: 5field, count: 3u8 } }",
+        )?;
+        let reader = plaintext.reader()?;
+
+        // Ensure the typed members are extracted.
+        assert_eq!(
+            reader.read_address("owner")?,
+            Address::from_str("aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah")?
+        );
+        assert_eq!(reader.read_u64("amount")?, 100);
+        assert!(reader.read_boolean("active")?);
+
+        // Ensure the nested members are extracted.
+        let data = reader.read_nested("data")?;
+        assert_eq!(data.read_field("id")?, Field::from_u64(5));
+        assert_eq!(data.read_u8("count")?, 3);
+
+        // Ensure a missing member is rejected.
+        let error = reader.read_u64("balance").unwrap_err();
+        assert_eq!(error.to_string(), "Missing member 'balance' in the interface");
+        // Ensure a mismatched type is rejected.
+        let error = reader.read_u32("amount").unwrap_err();
+        assert_eq!(error.to_string(), "Member 'amount' is not a u32, found '100u64'");
+        // Ensure a literal cannot be read as an interface.
+        let error = reader.read_nested("amount").map(|_| ()).unwrap_err();
+        assert_eq!(error.to_string(), "Member 'amount' is not an interface, found '100u64'");
+        // Ensure an interface cannot be read as a literal.
+        let error = reader.read_field("data").unwrap_err();
+        assert_eq!(error.to_string(), "Member 'data' is not a literal, found an interface");
+
+        // Ensure a literal cannot be read as an interface.
+        assert!(Plaintext::<CurrentNetwork>::from_str("100u64")?.reader().is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the number of bits of this plaintext, as encoded by `to_bits_le`.
+    pub fn size_in_bits(&self) -> usize {
+        match self {
+            // The variant bits, literal variant, literal size, and literal bits.
+            Self::Literal(literal, ..) => 2 + 8 + 16 + literal.size_in_bits() as usize,
+            // The variant bits, number of members, and each member.
+            Self::Interface(interface, ..) => interface.iter().fold(2 + 8, |size, (identifier, value)| {
+                // The identifier size, identifier bits, member size, and member bits.
+                size + 8 + identifier.size_in_bits() as usize + 16 + value.size_in_bits()
+            }),
+        }
+    }
+
+    /// Returns the number of bytes of this plaintext, as encoded by `to_bytes_le`.
+    pub fn size_in_bytes(&self) -> usize {
+        match self {
+            // The variant byte, literal variant, and literal bytes.
+            Self::Literal(literal, ..) => 1 + 2 + literal.size_in_bytes() as usize,
+            // The variant byte, number of members, and each member.
+            Self::Interface(interface, ..) => interface.iter().fold(1 + 2, |size, (identifier, value)| {
+                // The identifier size, identifier bytes, member size, and member bytes.
+                size + 1 + (identifier.size_in_bits() / 8) as usize + 2 + value.size_in_bytes()
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = Testnet3;
+
+    fn check_size(plaintext: &str) -> Result<()> {
+        let plaintext = Plaintext::<CurrentNetwork>::from_str(plaintext)?;
+        assert_eq!(plaintext.to_bits_le().len(), plaintext.size_in_bits());
+        assert_eq!(plaintext.to_bytes_le()?.len(), plaintext.size_in_bytes());
+        Ok(())
+    }
+
+    #[test]
+    fn test_size_literal() -> Result<()> {
+        check_size("true")?;
+        check_size("5u8")?;
+        check_size("-5i128")?;
+        check_size("1field")?;
+        check_size("2group")?;
+        check_size("3scalar")?;
+        check_size("aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah")?;
+        check_size("\"hello world\"")
+    }
+
+    #[test]
+    fn test_size_interface() -> Result<()> {
+        check_size("{ a: 1u8, b: 2u64 }")?;
+        check_size(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, token: { amount: 100u64, data: { flag: true, name: \"token\" } } }",
+        )
+    }
+}
@@ -16,6 +16,7 @@
 
 pub mod bls12;
 pub mod short_weierstrass_jacobian;
+pub(crate) mod sum_of_products;
 pub mod to_field_vec;
 pub mod twisted_edwards_extended;
 
@@ -53,6 +53,23 @@ impl<P: Parameters> Affine<P> {
     pub const fn new(x: P::BaseField, y: P::BaseField, infinity: bool) -> Self {
         Self { x, y, infinity }
     }
+
+    /// Deterministically maps the given field element to a point in the prime order subgroup.
+    ///
+    /// Starting from the given x-coordinate, this method increments the x-coordinate until it lies
+    /// on the curve, and then clears the cofactor. For a nothing-up-my-sleeve setup, the given field
+    /// element should be derived from a hash of the domain separator.
+    pub fn from_field(mut x: P::BaseField) -> Self {
+        loop {
+            if let Some(point) = Self::from_x_coordinate(x, false) {
+                let point = point.mul_by_cofactor();
+                if !point.is_zero() {
+                    return point;
+                }
+            }
+            x += P::BaseField::one();
+        }
+    }
 }
 
 impl<P: Parameters> Zero for Affine<P> {
@@ -15,13 +15,15 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+    errors::GroupError,
     templates::short_weierstrass_jacobian::Affine,
     traits::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
 };
-use snarkvm_fields::{impl_add_sub_from_field_ref, Field, One, Zero};
-use snarkvm_utilities::{rand::Uniform, serialize::*, FromBytes, ToBytes};
+use snarkvm_fields::{impl_add_sub_from_field_ref, Field, One, PrimeField, Zero};
+use snarkvm_utilities::{bititerator::BitIteratorBE, rand::Uniform, serialize::*, FromBytes, ToBytes};
 
 use core::{
+    borrow::Borrow,
     fmt::{Display, Formatter, Result as FmtResult},
     hash::{Hash, Hasher},
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
@@ -43,6 +45,75 @@ impl<P: Parameters> Projective<P> {
     pub const fn new(x: P::BaseField, y: P::BaseField, z: P::BaseField) -> Self {
         Self { x, y, z }
     }
+
+    /// Returns the affine equivalents of the given points, normalizing them in bulk with Montgomery's trick.
+    /// Unlike `batch_normalization`, this does not mutate the given points.
+    #[inline]
+    pub fn batch_into_affine(points: &[Self]) -> Vec<Affine<P>> {
+        Self::batch_normalization_into_affine(points.to_vec())
+    }
+
+    /// Returns a copy of `self` with `z == 1`, unless `self` is the point at infinity.
+    /// Two normalized points that are not the point at infinity are equal if and only if
+    /// their coordinates are equal, which is cheaper than the projective `PartialEq`.
+    #[inline]
+    pub fn to_normalized(&self) -> Self {
+        let mut point = *self;
+        point.normalize_in_place();
+        point
+    }
+
+    /// Normalizes `self` in place, such that `z == 1`, unless `self` is the point at infinity.
+    #[inline]
+    pub fn normalize_in_place(&mut self) {
+        Self::batch_normalization(core::slice::from_mut(self));
+    }
+
+    /// Returns `self` multiplied by the cofactor of the curve.
+    pub fn mul_by_cofactor(&self) -> Self {
+        let mut output = Self::zero();
+        for bit in BitIteratorBE::new_without_leading_zeros(P::COFACTOR) {
+            output.double_in_place();
+            if bit {
+                output += *self;
+            }
+        }
+        output
+    }
+
+    /// Returns `self` multiplied by the inverse of the cofactor, modulo the order of the prime order subgroup.
+    /// For a point in the prime order subgroup, this inverts `mul_by_cofactor`.
+    pub fn mul_by_cofactor_inv(&self) -> Self {
+        *self * P::COFACTOR_INV
+    }
+
+    /// Returns `p * a + q * b`, using interleaved double-and-add (Shamir's trick),
+    /// which doubles once per bit position, instead of once per bit of each scalar.
+    pub fn mul_double(p: &Self, a: &P::ScalarField, q: &Self, b: &P::ScalarField) -> Self {
+        // Precompute the sum of the bases.
+        let p_plus_q = *p + q;
+
+        let mut output = Self::zero();
+        let bits = BitIteratorBE::new(a.to_repr()).zip(BitIteratorBE::new(b.to_repr()));
+        for (bit_a, bit_b) in bits.skip_while(|(bit_a, bit_b)| !bit_a && !bit_b) {
+            output.double_in_place();
+            match (bit_a, bit_b) {
+                (true, true) => output += &p_plus_q,
+                (true, false) => output += p,
+                (false, true) => output += q,
+                (false, false) => (),
+            }
+        }
+        output
+    }
+
+    /// Returns the sum of products `bases[0] * scalars[0] + ... + bases[n-1] * scalars[n-1]`.
+    /// This uses Straus's method with 4-bit windows, which shares the doublings between every term,
+    /// and sums chunks of the terms in parallel if the `parallel` feature is enabled.
+    /// This is intended for small linear combinations; large inputs should use `VariableBase::msm`.
+    pub fn sum_of_products(bases: &[Self], scalars: &[P::ScalarField]) -> Result<Self, GroupError> {
+        crate::templates::sum_of_products::sum_of_products(bases, scalars)
+    }
 }
 
 impl<P: Parameters> Zero for Projective<P> {
@@ -482,6 +553,24 @@ impl<'a, P: Parameters> AddAssign<&'a Self> for Projective<P> {
     }
 }
 
+impl<'a, P: Parameters> Add<&'a Affine<P>> for Projective<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: &'a Affine<P>) -> Self {
+        let mut copy = self;
+        copy += other;
+        copy
+    }
+}
+
+impl<'a, P: Parameters> AddAssign<&'a Affine<P>> for Projective<P> {
+    #[inline]
+    fn add_assign(&mut self, other: &'a Affine<P>) {
+        self.add_assign_mixed(other);
+    }
+}
+
 impl<'a, P: Parameters> Sub<&'a Self> for Projective<P> {
     type Output = Self;
 
@@ -499,14 +588,44 @@ impl<'a, P: Parameters> SubAssign<&'a Self> for Projective<P> {
     }
 }
 
-impl<P: Parameters> Mul<P::ScalarField> for Projective<P> {
+impl<'a, P: Parameters> Sub<&'a Affine<P>> for Projective<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: &'a Affine<P>) -> Self {
+        let mut copy = self;
+        copy -= other;
+        copy
+    }
+}
+
+impl<'a, P: Parameters> SubAssign<&'a Affine<P>> for Projective<P> {
+    #[inline]
+    fn sub_assign(&mut self, other: &'a Affine<P>) {
+        self.add_assign_mixed(&(-(*other)));
+    }
+}
+
+/// Scalar multiplication accepts the scalar by value or by reference.
+/// Separate impls for `P::ScalarField` and `&P::ScalarField` would overlap, as `P::ScalarField` is a projection.
+impl<P: Parameters, S: Borrow<P::ScalarField>> Mul<S> for Projective<P> {
     type Output = Self;
 
     /// Performs scalar multiplication of this element.
     #[allow(clippy::suspicious_arithmetic_impl)]
     #[inline]
-    fn mul(self, other: P::ScalarField) -> Self {
-        P::mul_projective(self, other)
+    fn mul(self, other: S) -> Self {
+        P::mul_projective(self, *other.borrow())
+    }
+}
+
+impl<'a, P: Parameters> Mul<&'a P::ScalarField> for &Projective<P> {
+    type Output = Projective<P>;
+
+    /// Performs scalar multiplication of this element.
+    #[inline]
+    fn mul(self, other: &'a P::ScalarField) -> Projective<P> {
+        *self * *other
     }
 }
 
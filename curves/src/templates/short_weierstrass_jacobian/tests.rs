@@ -16,7 +16,7 @@
 
 use super::{Affine, Projective};
 use crate::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters};
-use snarkvm_fields::Zero;
+use snarkvm_fields::{One, Zero};
 use snarkvm_utilities::{
     io::Cursor,
     rand::Uniform,
@@ -31,6 +31,16 @@ pub const ITERATIONS: usize = 10;
 pub fn sw_tests<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
     sw_curve_serialization_test::<P>(rng);
     sw_from_random_bytes::<P>(rng);
+    sw_mixed_addition_test::<P>(rng);
+    sw_sum_of_products_test::<P>(rng);
+    sw_mul_by_reference_test::<P>(rng);
+    sw_normalization_test::<P>(rng);
+    sw_batch_into_affine_test::<P>(rng);
+    sw_from_field_test::<P>(rng);
+    sw_mul_by_cofactor_test::<P>(rng);
+    sw_sum_test::<P>(rng);
+    sw_mul_double_test::<P>(rng);
+    sw_mixed_subtraction_test::<P>(rng);
 }
 
 pub fn sw_curve_serialization_test<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
@@ -140,3 +150,205 @@ pub fn sw_from_random_bytes<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
         }
     }
 }
+
+pub fn sw_mixed_addition_test<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
+    for _ in 0..ITERATIONS {
+        let p = Projective::<P>::rand(rng);
+        let a = Projective::<P>::rand(rng).to_affine();
+        let b = Projective::<P>::rand(rng).to_affine();
+
+        // Compute the expected sum using mixed addition.
+        let mut expected = p;
+        expected.add_assign_mixed(&a);
+        expected.add_assign_mixed(&b);
+
+        // Check the `AddAssign` operator.
+        let mut candidate = p;
+        candidate += &a;
+        candidate += &b;
+        assert_eq!(expected, candidate);
+
+        // Check the `Add` operator.
+        let candidate = p + &a + &b;
+        assert_eq!(expected, candidate);
+    }
+}
+
+pub fn sw_sum_of_products_test<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
+    for num_terms in [0, 1, 2, 5, 16, 37] {
+        let bases = (0..num_terms).map(|_| Projective::<P>::rand(rng)).collect::<Vec<_>>();
+        let scalars = (0..num_terms).map(|_| P::ScalarField::rand(rng)).collect::<Vec<_>>();
+
+        // Compute the naive fold.
+        let expected =
+            bases.iter().zip(&scalars).fold(Projective::<P>::zero(), |sum, (base, scalar)| sum + *base * *scalar);
+
+        // Ensure the sum of products matches the naive fold.
+        assert_eq!(expected, Projective::<P>::sum_of_products(&bases, &scalars).unwrap());
+    }
+
+    // Check the edge cases.
+    let bases = vec![Projective::<P>::zero(), Projective::<P>::rand(rng), Projective::<P>::rand(rng)];
+    let scalars = vec![P::ScalarField::rand(rng), P::ScalarField::zero(), -P::ScalarField::one()];
+    assert_eq!(-bases[2], Projective::<P>::sum_of_products(&bases, &scalars).unwrap());
+
+    // Ensure mismatched lengths are rejected.
+    let bases = vec![Projective::<P>::rand(rng); 2];
+    let scalars = vec![P::ScalarField::rand(rng)];
+    assert!(Projective::<P>::sum_of_products(&bases, &scalars).is_err());
+}
+
+#[allow(clippy::op_ref)]
+pub fn sw_mul_by_reference_test<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
+    for _ in 0..ITERATIONS {
+        let p = Projective::<P>::rand(rng);
+        let scalar = P::ScalarField::rand(rng);
+
+        // Ensure the reference-based multiplication matches the by-value multiplication.
+        assert_eq!(p * scalar, p * &scalar);
+        assert_eq!(p * scalar, &p * &scalar);
+    }
+}
+
+pub fn sw_normalization_test<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
+    for _ in 0..ITERATIONS {
+        // Sample a point that is not normalized.
+        let p = Projective::<P>::rand(rng).double();
+        assert!(!p.is_normalized());
+
+        // Ensure the normalized point equals the original.
+        let normalized = p.to_normalized();
+        assert!(normalized.is_normalized());
+        assert_eq!(p, normalized);
+        assert_eq!(p.to_affine(), normalized.to_affine());
+
+        // Ensure normalizing in place matches.
+        let mut q = p;
+        q.normalize_in_place();
+        assert!(q.is_normalized());
+        assert_eq!((q.x, q.y, q.z), (normalized.x, normalized.y, normalized.z));
+    }
+
+    // Ensure the zero point is normalized.
+    assert!(Projective::<P>::zero().to_normalized().is_zero());
+}
+
+pub fn sw_batch_into_affine_test<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
+    // Sample random points, including the identity.
+    let mut points = (0..50).map(|_| Projective::<P>::rand(rng).double()).collect::<Vec<_>>();
+    points[0] = Projective::<P>::zero();
+    points[25] = Projective::<P>::zero();
+
+    // Ensure the batch conversion matches the individual conversions.
+    let expected = points.iter().map(|point| point.to_affine()).collect::<Vec<_>>();
+    let candidate = Projective::<P>::batch_into_affine(&points);
+    assert_eq!(expected, candidate);
+    assert!(candidate[0].is_zero());
+    assert!(candidate[25].is_zero());
+
+    // Ensure the given points are not mutated.
+    assert!(!points[1].is_normalized());
+
+    // Ensure an empty slice is supported.
+    assert!(Projective::<P>::batch_into_affine(&[]).is_empty());
+}
+
+pub fn sw_from_field_test<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
+    for _ in 0..ITERATIONS {
+        let x = P::BaseField::rand(rng);
+
+        // Ensure the map is deterministic.
+        let a = Affine::<P>::from_field(x);
+        assert_eq!(a, Affine::<P>::from_field(x));
+
+        // Ensure the output is a nonzero point in the prime order subgroup.
+        assert!(!a.is_zero());
+        assert!(a.is_on_curve());
+        assert!(a.is_in_correct_subgroup_assuming_on_curve());
+
+        // Ensure distinct inputs map to distinct points.
+        let b = Affine::<P>::from_field(P::BaseField::rand(rng));
+        assert_ne!(a, b);
+    }
+}
+
+pub fn sw_mul_by_cofactor_test<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
+    for _ in 0..ITERATIONS {
+        // Sample a point in the prime order subgroup.
+        let p = Projective::<P>::rand(rng);
+
+        // Ensure the cofactor multiplication matches the affine cofactor multiplication.
+        assert_eq!(p.mul_by_cofactor(), p.to_affine().mul_by_cofactor_to_projective());
+        // Ensure the inverse cofactor multiplication matches the affine inverse cofactor multiplication.
+        assert_eq!(p.mul_by_cofactor_inv(), p.to_affine().mul_by_cofactor_inv());
+
+        // Ensure the cofactor multiplication round-trips.
+        assert_eq!(p, p.mul_by_cofactor().mul_by_cofactor_inv());
+        assert_eq!(p, p.mul_by_cofactor_inv().mul_by_cofactor());
+    }
+
+    // Ensure the identity is preserved.
+    assert!(Projective::<P>::zero().mul_by_cofactor().is_zero());
+    assert!(Projective::<P>::zero().mul_by_cofactor_inv().is_zero());
+}
+
+pub fn sw_sum_test<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
+    for num_points in [0, 1, 2, 10] {
+        let points = (0..num_points).map(|_| Projective::<P>::rand(rng)).collect::<Vec<_>>();
+
+        // Compute the sum with an explicit fold.
+        let expected = points.iter().fold(Projective::<P>::zero(), |mut sum, point| {
+            sum += point;
+            sum
+        });
+
+        // Ensure the sums by value and by reference match the fold.
+        assert_eq!(points.iter().sum::<Projective<P>>(), expected);
+        assert_eq!(points.into_iter().sum::<Projective<P>>(), expected);
+    }
+
+    // Ensure the empty sum is the identity.
+    assert!(core::iter::empty::<Projective<P>>().sum::<Projective<P>>().is_zero());
+}
+
+pub fn sw_mul_double_test<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
+    for _ in 0..ITERATIONS {
+        let (p, q) = (Projective::<P>::rand(rng), Projective::<P>::rand(rng));
+        let (a, b) = (P::ScalarField::rand(rng), P::ScalarField::rand(rng));
+
+        // Ensure the double-scalar multiplication matches two scalar multiplications.
+        assert_eq!(Projective::mul_double(&p, &a, &q, &b), p * a + q * b);
+        // Ensure a zero scalar is handled.
+        assert_eq!(Projective::mul_double(&p, &P::ScalarField::zero(), &q, &b), q * b);
+        assert_eq!(Projective::mul_double(&p, &a, &q, &P::ScalarField::zero()), p * a);
+        // Ensure equal bases are handled.
+        assert_eq!(Projective::mul_double(&p, &a, &p, &b), p * (a + b));
+    }
+
+    // Ensure zero scalars yield the identity.
+    let zero = P::ScalarField::zero();
+    assert!(Projective::mul_double(&Projective::<P>::rand(rng), &zero, &Projective::<P>::rand(rng), &zero).is_zero());
+}
+
+pub fn sw_mixed_subtraction_test<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
+    for _ in 0..ITERATIONS {
+        let (a, b) = (Projective::<P>::rand(rng), Projective::<P>::rand(rng));
+        let b_affine = b.to_affine();
+
+        // Ensure the affine negation matches the projective negation.
+        assert_eq!(-b_affine, (-b).to_affine());
+
+        // Ensure the mixed subtraction matches the projective subtraction.
+        assert_eq!(a - &b_affine, a - b);
+        let mut c = a;
+        c -= &b_affine;
+        assert_eq!(c, a - b);
+
+        // Ensure subtracting a point from itself yields the identity.
+        assert!((b - &b_affine).is_zero());
+    }
+
+    // Ensure subtracting the identity is a no-op.
+    let a = Projective::<P>::rand(rng);
+    assert_eq!(a - &Affine::<P>::zero(), a);
+}
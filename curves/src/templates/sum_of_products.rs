@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::GroupError, traits::ProjectiveCurve};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{bititerator::BitIteratorBE, cfg_chunks, cfg_reduce};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The number of bits in each window.
+const WINDOW_SIZE: usize = 4;
+/// The number of nonzero multiples of each base.
+const TABLE_SIZE: usize = (1 << WINDOW_SIZE) - 1;
+/// The minimum number of terms in each parallel chunk, as every chunk repeats the doublings.
+#[cfg(feature = "parallel")]
+const MIN_CHUNK_SIZE: usize = 16;
+
+/// Returns the sum of products `bases[0] * scalars[0] + ... + bases[n-1] * scalars[n-1]`.
+/// The terms are split into chunks, which are summed in parallel if the `parallel` feature is enabled.
+pub(crate) fn sum_of_products<G: ProjectiveCurve>(bases: &[G], scalars: &[G::ScalarField]) -> Result<G, GroupError> {
+    // Ensure the number of bases matches the number of scalars.
+    if bases.len() != scalars.len() {
+        return Err(GroupError::Message(format!(
+            "Mismatching number of bases ({}) and scalars ({})",
+            bases.len(),
+            scalars.len()
+        )));
+    }
+
+    // Determine the number of terms in each chunk.
+    #[cfg(feature = "parallel")]
+    let chunk_size = {
+        let num_threads = rayon::current_num_threads();
+        MIN_CHUNK_SIZE.max((bases.len() + num_threads - 1) / num_threads)
+    };
+    #[cfg(not(feature = "parallel"))]
+    let chunk_size = bases.len().max(1);
+
+    // Compute the sum of products of each chunk, and sum them.
+    let sums = cfg_chunks!(bases, chunk_size)
+        .zip(cfg_chunks!(scalars, chunk_size))
+        .map(|(bases, scalars)| straus(bases, scalars));
+    Ok(cfg_reduce!(sums, G::zero, |a, b| a + b))
+}
+
+/// Returns the sum of products of the given bases and scalars, which must have the same length.
+/// This uses Straus's method with 4-bit windows, which shares the doublings between every term.
+fn straus<G: ProjectiveCurve>(bases: &[G], scalars: &[G::ScalarField]) -> G {
+    // Precompute the multiples `[base, 2 * base, ..., TABLE_SIZE * base]` of each base.
+    let mut tables = Vec::with_capacity(bases.len() * TABLE_SIZE);
+    for base in bases {
+        let mut multiple = *base;
+        tables.push(multiple);
+        for _ in 1..TABLE_SIZE {
+            multiple += base;
+            tables.push(multiple);
+        }
+    }
+    // Normalize the multiples, to use mixed addition in the main loop.
+    let tables = G::batch_normalization_into_affine(tables);
+
+    // Decompose each scalar into its windows, from the most significant window.
+    let digits = scalars
+        .iter()
+        .map(|scalar| {
+            let bits = BitIteratorBE::new(scalar.to_repr()).collect::<Vec<_>>();
+            bits.rchunks(WINDOW_SIZE)
+                .rev()
+                .map(|window| window.iter().fold(0, |digit, bit| (digit << 1) | usize::from(*bit)))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // Compute the sum of products, with one set of doublings for all of the terms.
+    let num_windows = digits.first().map_or(0, Vec::len);
+    let mut output = G::zero();
+    for window in 0..num_windows {
+        for _ in 0..WINDOW_SIZE {
+            output.double_in_place();
+        }
+        for (table, digits) in tables.chunks(TABLE_SIZE).zip(&digits) {
+            if digits[window] != 0 {
+                output.add_assign_mixed(&table[digits[window] - 1]);
+            }
+        }
+    }
+    output
+}
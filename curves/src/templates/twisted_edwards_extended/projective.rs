@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+    errors::GroupError,
     templates::twisted_edwards_extended::Affine,
     traits::{AffineCurve, ProjectiveCurve, TwistedEdwardsParameters as Parameters},
 };
@@ -22,6 +23,7 @@ use snarkvm_fields::{impl_add_sub_from_field_ref, Field, One, PrimeField, Zero};
 use snarkvm_utilities::{bititerator::BitIteratorBE, rand::Uniform, serialize::*, FromBytes, ToBytes};
 
 use core::{
+    borrow::Borrow,
     fmt::{Display, Formatter, Result as FmtResult},
     hash::{Hash, Hasher},
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
@@ -30,7 +32,7 @@ use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
-use std::io::{Read, Result as IoResult, Write};
+use std::io::{Error as IoError, ErrorKind, Read, Result as IoResult, Write};
 
 #[derive(Copy, Clone, Debug)]
 pub struct Projective<P: Parameters> {
@@ -45,6 +47,99 @@ impl<P: Parameters> Projective<P> {
     pub const fn new(x: P::BaseField, y: P::BaseField, t: P::BaseField, z: P::BaseField) -> Self {
         Self { x, y, t, z }
     }
+
+    /// Returns the sum of products `bases[0] * scalars[0] + ... + bases[n-1] * scalars[n-1]`.
+    /// This uses Straus's method with 4-bit windows, which shares the doublings between every term,
+    /// and sums chunks of the terms in parallel if the `parallel` feature is enabled.
+    /// This is intended for small linear combinations; large inputs should use `VariableBase::msm`.
+    pub fn sum_of_products(bases: &[Self], scalars: &[P::ScalarField]) -> Result<Self, GroupError> {
+        crate::templates::sum_of_products::sum_of_products(bases, scalars)
+    }
+
+    /// Returns the affine equivalents of the given points, normalizing them in bulk with Montgomery's trick.
+    /// Unlike `batch_normalization`, this does not mutate the given points.
+    #[inline]
+    pub fn batch_into_affine(points: &[Self]) -> Vec<Affine<P>> {
+        Self::batch_normalization_into_affine(points.to_vec())
+    }
+
+    /// Returns a copy of `self` with `z == 1`.
+    /// Two normalized points are equal if and only if their coordinates are equal,
+    /// which is cheaper than the projective `PartialEq`.
+    #[inline]
+    pub fn to_normalized(&self) -> Self {
+        let mut point = *self;
+        point.normalize_in_place();
+        point
+    }
+
+    /// Normalizes `self` in place, such that `z == 1`.
+    #[inline]
+    pub fn normalize_in_place(&mut self) {
+        Self::batch_normalization(core::slice::from_mut(self));
+    }
+
+    /// Returns `self` multiplied by the cofactor of the curve.
+    pub fn mul_by_cofactor(&self) -> Self {
+        let mut output = Self::zero();
+        for bit in BitIteratorBE::new_without_leading_zeros(P::COFACTOR) {
+            output.double_in_place();
+            if bit {
+                output += *self;
+            }
+        }
+        output
+    }
+
+    /// Returns `self` multiplied by the inverse of the cofactor, modulo the order of the prime order subgroup.
+    /// For a point in the prime order subgroup, this inverts `mul_by_cofactor`.
+    pub fn mul_by_cofactor_inv(&self) -> Self {
+        *self * P::COFACTOR_INV
+    }
+
+    /// Returns `p * a + q * b`, using interleaved double-and-add (Shamir's trick),
+    /// which doubles once per bit position, instead of once per bit of each scalar.
+    pub fn mul_double(p: &Self, a: &P::ScalarField, q: &Self, b: &P::ScalarField) -> Self {
+        // Precompute the sum of the bases.
+        let p_plus_q = *p + q;
+
+        let mut output = Self::zero();
+        let bits = BitIteratorBE::new(a.to_repr()).zip(BitIteratorBE::new(b.to_repr()));
+        for (bit_a, bit_b) in bits.skip_while(|(bit_a, bit_b)| !bit_a && !bit_b) {
+            output.double_in_place();
+            match (bit_a, bit_b) {
+                (true, true) => output += &p_plus_q,
+                (true, false) => output += p,
+                (false, true) => output += q,
+                (false, false) => (),
+            }
+        }
+        output
+    }
+
+    /// Returns `true` if `self` satisfies the twisted Edwards equation in extended coordinates,
+    /// `(a * x^2 + y^2) * z^2 == z^4 + d * x^2 * y^2`, and `t` is consistent with `t * z == x * y`.
+    /// A point with `z == 0` satisfies both equations trivially, so it is rejected explicitly.
+    fn is_on_curve(&self) -> bool {
+        let x2 = self.x.square();
+        let y2 = self.y.square();
+        let z2 = self.z.square();
+
+        let lhs = (P::mul_by_a(&x2) + y2) * z2;
+        let rhs = z2.square() + P::EDWARDS_D * (x2 * y2);
+
+        !self.z.is_zero() && lhs == rhs && self.t * self.z == self.x * self.y
+    }
+
+    /// Returns the projective coordinates of `self` as a string.
+    pub fn debug_projective(&self) -> String {
+        format!("Projective(x={}, y={}, t={}, z={})", self.x, self.y, self.t, self.z)
+    }
+
+    /// Returns the affine coordinates of `self` as a string.
+    pub fn debug_affine(&self) -> String {
+        self.to_affine().to_string()
+    }
 }
 
 impl<P: Parameters> Zero for Projective<P> {
@@ -139,7 +234,12 @@ impl<P: Parameters> FromBytes for Projective<P> {
         let y = P::BaseField::read_le(&mut reader)?;
         let t = P::BaseField::read_le(&mut reader)?;
         let z = P::BaseField::read_le(reader)?;
-        Ok(Self::new(x, y, t, z))
+        // Ensure the point is on the curve.
+        let point = Self::new(x, y, t, z);
+        match point.is_on_curve() {
+            true => Ok(point),
+            false => Err(IoError::new(ErrorKind::InvalidData, GroupError::InvalidGroupElement)),
+        }
     }
 }
 
@@ -348,6 +448,22 @@ impl<'a, P: Parameters> AddAssign<&'a Self> for Projective<P> {
     }
 }
 
+impl<'a, P: Parameters> Add<&'a Affine<P>> for Projective<P> {
+    type Output = Self;
+
+    fn add(self, other: &'a Affine<P>) -> Self {
+        let mut copy = self;
+        copy += other;
+        copy
+    }
+}
+
+impl<'a, P: Parameters> AddAssign<&'a Affine<P>> for Projective<P> {
+    fn add_assign(&mut self, other: &'a Affine<P>) {
+        self.add_assign_mixed(other);
+    }
+}
+
 impl<'a, P: Parameters> Sub<&'a Self> for Projective<P> {
     type Output = Self;
 
@@ -364,13 +480,32 @@ impl<'a, P: Parameters> SubAssign<&'a Self> for Projective<P> {
     }
 }
 
-impl<P: Parameters> Mul<P::ScalarField> for Projective<P> {
+impl<'a, P: Parameters> Sub<&'a Affine<P>> for Projective<P> {
+    type Output = Self;
+
+    fn sub(self, other: &'a Affine<P>) -> Self {
+        let mut copy = self;
+        copy -= other;
+        copy
+    }
+}
+
+impl<'a, P: Parameters> SubAssign<&'a Affine<P>> for Projective<P> {
+    fn sub_assign(&mut self, other: &'a Affine<P>) {
+        self.add_assign_mixed(&(-(*other)));
+    }
+}
+
+/// Scalar multiplication accepts the scalar by value or by reference.
+/// Separate impls for `P::ScalarField` and `&P::ScalarField` would overlap, as `P::ScalarField` is a projection.
+impl<P: Parameters, S: Borrow<P::ScalarField>> Mul<S> for Projective<P> {
     type Output = Self;
 
     /// Performs scalar multiplication of this element.
     #[allow(clippy::suspicious_arithmetic_impl)]
     #[inline]
-    fn mul(self, other: P::ScalarField) -> Self {
+    fn mul(self, other: S) -> Self {
+        let other = other.borrow();
         let mut res = Self::zero();
 
         let mut found_one = false;
@@ -391,6 +526,16 @@ impl<P: Parameters> Mul<P::ScalarField> for Projective<P> {
     }
 }
 
+impl<'a, P: Parameters> Mul<&'a P::ScalarField> for &Projective<P> {
+    type Output = Projective<P>;
+
+    /// Performs scalar multiplication of this element.
+    #[inline]
+    fn mul(self, other: &'a P::ScalarField) -> Projective<P> {
+        *self * *other
+    }
+}
+
 impl<P: Parameters> MulAssign<P::ScalarField> for Projective<P> {
     /// Performs scalar multiplication of this element.
     fn mul_assign(&mut self, other: P::ScalarField) {
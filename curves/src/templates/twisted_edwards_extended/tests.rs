@@ -22,6 +22,7 @@ use snarkvm_utilities::{
     serialize::{CanonicalDeserialize, CanonicalSerialize},
     to_bytes_le,
     Compress,
+    FromBytes,
     TestRng,
     ToBytes,
     Validate,
@@ -53,6 +54,19 @@ where
     edwards_curve_serialization_test::<P>(rng);
     edwards_from_random_bytes::<P>(rng);
     edwards_from_x_and_y_coordinates::<P>(rng);
+    edwards_mixed_addition_test::<P>(rng);
+    edwards_debug_test::<P>(rng);
+    edwards_mul_by_reference_test::<P>(rng);
+    edwards_normalization_test::<P>(rng);
+    edwards_batch_into_affine_test::<P>(rng);
+    edwards_read_le_on_curve_test::<P>(rng);
+    edwards_sum_of_products_test::<P>(rng);
+    edwards_from_field_test::<P>(rng);
+    edwards_mul_by_cofactor_test::<P>(rng);
+    edwards_sum_test::<P>(rng);
+    edwards_mul_double_test::<P>(rng);
+    edwards_mixed_subtraction_test::<P>(rng);
+    edwards_double_test::<P>(rng);
 }
 
 pub fn edwards_curve_serialization_test<P: TwistedEdwardsParameters>(rng: &mut TestRng) {
@@ -214,3 +228,280 @@ where
         }
     }
 }
+
+pub fn edwards_mixed_addition_test<P: TwistedEdwardsParameters>(rng: &mut TestRng) {
+    for _ in 0..ITERATIONS {
+        let p = Projective::<P>::rand(rng);
+        let a = Projective::<P>::rand(rng).to_affine();
+        let b = Projective::<P>::rand(rng).to_affine();
+
+        // Compute the expected sum using mixed addition.
+        let mut expected = p;
+        expected.add_assign_mixed(&a);
+        expected.add_assign_mixed(&b);
+
+        // Check the `AddAssign` operator.
+        let mut candidate = p;
+        candidate += &a;
+        candidate += &b;
+        assert_eq!(expected, candidate);
+
+        // Check the `Add` operator.
+        let candidate = p + &a + &b;
+        assert_eq!(expected, candidate);
+    }
+}
+
+pub fn edwards_debug_test<P: TwistedEdwardsParameters>(rng: &mut TestRng) {
+    let p = Projective::<P>::rand(rng);
+
+    // Ensure the projective coordinates are printed as-is.
+    let expected = format!("Projective(x={}, y={}, t={}, z={})", p.x, p.y, p.t, p.z);
+    assert_eq!(expected, p.debug_projective());
+
+    // Ensure the affine coordinates are printed after normalization.
+    let affine = p.to_affine();
+    let expected = format!("Affine(x={}, y={})", affine.x, affine.y);
+    assert_eq!(expected, p.debug_affine());
+    assert_eq!(p.to_string(), p.debug_affine());
+
+    // Ensure the derived `Debug` labels the coordinates.
+    let debug = format!("{p:?}");
+    assert!(debug.contains("x: ") && debug.contains("y: ") && debug.contains("t: ") && debug.contains("z: "));
+}
+
+#[allow(clippy::op_ref)]
+pub fn edwards_mul_by_reference_test<P: TwistedEdwardsParameters>(rng: &mut TestRng) {
+    for _ in 0..ITERATIONS {
+        let p = Projective::<P>::rand(rng);
+        let scalar = P::ScalarField::rand(rng);
+
+        // Ensure the reference-based multiplication matches the by-value multiplication.
+        assert_eq!(p * scalar, p * &scalar);
+        assert_eq!(p * scalar, &p * &scalar);
+    }
+}
+
+pub fn edwards_normalization_test<P: TwistedEdwardsParameters>(rng: &mut TestRng) {
+    for _ in 0..ITERATIONS {
+        // Sample a point that is not normalized.
+        let p = Projective::<P>::rand(rng).double();
+        assert!(!p.is_normalized());
+
+        // Ensure the normalized point equals the original.
+        let normalized = p.to_normalized();
+        assert!(normalized.is_normalized());
+        assert_eq!(p, normalized);
+        assert_eq!(p.to_affine(), normalized.to_affine());
+
+        // Ensure normalizing in place matches.
+        let mut q = p;
+        q.normalize_in_place();
+        assert!(q.is_normalized());
+        assert_eq!((q.x, q.y, q.z), (normalized.x, normalized.y, normalized.z));
+    }
+
+    // Ensure the zero point is normalized.
+    assert!(Projective::<P>::zero().to_normalized().is_zero());
+}
+
+pub fn edwards_batch_into_affine_test<P: TwistedEdwardsParameters>(rng: &mut TestRng) {
+    // Sample random points, including the identity.
+    let mut points = (0..50).map(|_| Projective::<P>::rand(rng).double()).collect::<Vec<_>>();
+    points[0] = Projective::<P>::zero();
+    points[25] = Projective::<P>::zero();
+
+    // Ensure the batch conversion matches the individual conversions.
+    let expected = points.iter().map(|point| point.to_affine()).collect::<Vec<_>>();
+    let candidate = Projective::<P>::batch_into_affine(&points);
+    assert_eq!(expected, candidate);
+    assert!(candidate[0].is_zero());
+    assert!(candidate[25].is_zero());
+
+    // Ensure the given points are not mutated.
+    assert!(!points[1].is_normalized());
+
+    // Ensure an empty slice is supported.
+    assert!(Projective::<P>::batch_into_affine(&[]).is_empty());
+}
+
+pub fn edwards_read_le_on_curve_test<P: TwistedEdwardsParameters>(rng: &mut TestRng) {
+    for _ in 0..ITERATIONS {
+        // Ensure a valid point round-trips, whether or not it is normalized.
+        let p = Projective::<P>::rand(rng);
+        assert_eq!(p, Projective::<P>::read_le(&to_bytes_le![p].unwrap()[..]).unwrap());
+        let q = p.double();
+        assert_eq!(q, Projective::<P>::read_le(&to_bytes_le![q].unwrap()[..]).unwrap());
+
+        // Ensure a point with a corrupted coordinate is rejected.
+        let mut corrupted = p;
+        corrupted.y += P::BaseField::one();
+        let error = Projective::<P>::read_le(&to_bytes_le![corrupted].unwrap()[..]).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+
+        // Ensure a point with an inconsistent `t` coordinate is rejected.
+        let mut corrupted = p;
+        corrupted.t += P::BaseField::one();
+        let error = Projective::<P>::read_le(&to_bytes_le![corrupted].unwrap()[..]).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    // Ensure the identity round-trips.
+    let zero = Projective::<P>::zero();
+    let bytes = to_bytes_le![zero].unwrap();
+    assert!(Projective::<P>::read_le(&bytes[..]).unwrap().is_zero());
+
+    // Ensure an all-zero buffer is rejected, as a point with `z == 0` trivially satisfies the curve equation.
+    let buffer = vec![0u8; bytes.len()];
+    let error = Projective::<P>::read_le(&buffer[..]).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+
+    // Ensure a truncated buffer is rejected.
+    let bytes = to_bytes_le![Projective::<P>::rand(rng)].unwrap();
+    assert!(Projective::<P>::read_le(&bytes[1..]).is_err());
+}
+
+pub fn edwards_from_field_test<P: TwistedEdwardsParameters>(rng: &mut TestRng) {
+    for _ in 0..ITERATIONS {
+        let x = P::BaseField::rand(rng);
+
+        // Ensure the map is deterministic.
+        let a = Affine::<P>::from_field(x);
+        assert_eq!(a, Affine::<P>::from_field(x));
+
+        // Ensure the output is a nonzero point in the prime order subgroup.
+        assert!(!a.is_zero());
+        assert!(a.is_on_curve());
+        assert!(a.is_in_correct_subgroup_assuming_on_curve());
+
+        // Ensure distinct inputs map to distinct points.
+        let b = Affine::<P>::from_field(P::BaseField::rand(rng));
+        assert_ne!(a, b);
+    }
+}
+
+pub fn edwards_mul_by_cofactor_test<P: TwistedEdwardsParameters>(rng: &mut TestRng) {
+    for _ in 0..ITERATIONS {
+        // Sample a point in the prime order subgroup.
+        let p = Projective::<P>::rand(rng);
+
+        // Ensure the cofactor multiplication matches the affine cofactor multiplication.
+        assert_eq!(p.mul_by_cofactor(), p.to_affine().mul_by_cofactor_to_projective());
+        // Ensure the inverse cofactor multiplication matches the affine inverse cofactor multiplication.
+        assert_eq!(p.mul_by_cofactor_inv(), p.to_affine().mul_by_cofactor_inv());
+
+        // Ensure the cofactor multiplication round-trips.
+        assert_eq!(p, p.mul_by_cofactor().mul_by_cofactor_inv());
+        assert_eq!(p, p.mul_by_cofactor_inv().mul_by_cofactor());
+    }
+
+    // Ensure the identity is preserved.
+    assert!(Projective::<P>::zero().mul_by_cofactor().is_zero());
+    assert!(Projective::<P>::zero().mul_by_cofactor_inv().is_zero());
+}
+
+pub fn edwards_sum_test<P: TwistedEdwardsParameters>(rng: &mut TestRng) {
+    for num_points in [0, 1, 2, 10] {
+        let points = (0..num_points).map(|_| Projective::<P>::rand(rng)).collect::<Vec<_>>();
+
+        // Compute the sum with an explicit fold.
+        let expected = points.iter().fold(Projective::<P>::zero(), |mut sum, point| {
+            sum += point;
+            sum
+        });
+
+        // Ensure the sums by value and by reference match the fold.
+        assert_eq!(points.iter().sum::<Projective<P>>(), expected);
+        assert_eq!(points.into_iter().sum::<Projective<P>>(), expected);
+    }
+
+    // Ensure the empty sum is the identity.
+    assert!(core::iter::empty::<Projective<P>>().sum::<Projective<P>>().is_zero());
+}
+
+pub fn edwards_mul_double_test<P: TwistedEdwardsParameters>(rng: &mut TestRng) {
+    for _ in 0..ITERATIONS {
+        let (p, q) = (Projective::<P>::rand(rng), Projective::<P>::rand(rng));
+        let (a, b) = (P::ScalarField::rand(rng), P::ScalarField::rand(rng));
+
+        // Ensure the double-scalar multiplication matches two scalar multiplications.
+        assert_eq!(Projective::mul_double(&p, &a, &q, &b), p * a + q * b);
+        // Ensure a zero scalar is handled.
+        assert_eq!(Projective::mul_double(&p, &P::ScalarField::zero(), &q, &b), q * b);
+        assert_eq!(Projective::mul_double(&p, &a, &q, &P::ScalarField::zero()), p * a);
+        // Ensure equal bases are handled.
+        assert_eq!(Projective::mul_double(&p, &a, &p, &b), p * (a + b));
+    }
+
+    // Ensure zero scalars yield the identity.
+    let zero = P::ScalarField::zero();
+    assert!(Projective::mul_double(&Projective::<P>::rand(rng), &zero, &Projective::<P>::rand(rng), &zero).is_zero());
+}
+
+pub fn edwards_double_test<P: TwistedEdwardsParameters>(rng: &mut TestRng) {
+    for _ in 0..ITERATIONS {
+        let p = Projective::<P>::rand(rng);
+
+        // Ensure the dedicated doubling matches the unified addition.
+        assert_eq!(p.double(), p + p);
+        let mut q = p;
+        q.double_in_place();
+        assert_eq!(q, p + p);
+    }
+
+    // Ensure the identity is preserved.
+    assert!(Projective::<P>::zero().double().is_zero());
+
+    // Ensure the point of order two, (0, -1), doubles to the identity.
+    let p = Projective::<P>::new(P::BaseField::zero(), -P::BaseField::one(), P::BaseField::zero(), P::BaseField::one());
+    assert!(!p.is_zero());
+    assert!(p.double().is_zero());
+    assert_eq!(p.double(), p + p);
+}
+
+pub fn edwards_mixed_subtraction_test<P: TwistedEdwardsParameters>(rng: &mut TestRng) {
+    for _ in 0..ITERATIONS {
+        let (a, b) = (Projective::<P>::rand(rng), Projective::<P>::rand(rng));
+        let b_affine = b.to_affine();
+
+        // Ensure the affine negation matches the projective negation.
+        assert_eq!(-b_affine, (-b).to_affine());
+
+        // Ensure the mixed subtraction matches the projective subtraction.
+        assert_eq!(a - &b_affine, a - b);
+        let mut c = a;
+        c -= &b_affine;
+        assert_eq!(c, a - b);
+
+        // Ensure subtracting a point from itself yields the identity.
+        assert!((b - &b_affine).is_zero());
+    }
+
+    // Ensure subtracting the identity is a no-op.
+    let a = Projective::<P>::rand(rng);
+    assert_eq!(a - &Affine::<P>::zero(), a);
+}
+
+pub fn edwards_sum_of_products_test<P: TwistedEdwardsParameters>(rng: &mut TestRng) {
+    for num_terms in [0, 1, 2, 5, 16, 37] {
+        let bases = (0..num_terms).map(|_| Projective::<P>::rand(rng)).collect::<Vec<_>>();
+        let scalars = (0..num_terms).map(|_| P::ScalarField::rand(rng)).collect::<Vec<_>>();
+
+        // Compute the naive fold.
+        let expected =
+            bases.iter().zip(&scalars).fold(Projective::<P>::zero(), |sum, (base, scalar)| sum + *base * *scalar);
+
+        // Ensure the sum of products matches the naive fold.
+        assert_eq!(expected, Projective::<P>::sum_of_products(&bases, &scalars).unwrap());
+    }
+
+    // Check the edge cases.
+    let bases = vec![Projective::<P>::zero(), Projective::<P>::rand(rng), Projective::<P>::rand(rng)];
+    let scalars = vec![P::ScalarField::rand(rng), P::ScalarField::zero(), -P::ScalarField::one()];
+    assert_eq!(-bases[2], Projective::<P>::sum_of_products(&bases, &scalars).unwrap());
+
+    // Ensure mismatched lengths are rejected.
+    let bases = vec![Projective::<P>::rand(rng); 2];
+    let scalars = vec![P::ScalarField::rand(rng)];
+    assert!(Projective::<P>::sum_of_products(&bases, &scalars).is_err());
+}
@@ -23,6 +23,37 @@ impl<N: Network> Block<N> {
         vm: &VM<N, P>,
         private_key: &PrivateKey<N>,
         rng: &mut R,
+    ) -> Result<Self> {
+        Self::genesis_with_timestamp(vm, private_key, N::GENESIS_TIMESTAMP, rng)
+    }
+
+    /// Initializes a new genesis block, with the given timestamp.
+    pub fn genesis_with_timestamp<P: ProgramStorage<N>, R: Rng + CryptoRng>(
+        vm: &VM<N, P>,
+        private_key: &PrivateKey<N>,
+        timestamp: i64,
+        rng: &mut R,
+    ) -> Result<Self> {
+        Self::genesis_with_supply_and_timestamp(vm, private_key, 1_100_000_000_000_000, timestamp, rng)
+    }
+
+    /// Initializes a new genesis block, which mints the given starting supply to the caller.
+    pub fn genesis_with_supply<P: ProgramStorage<N>, R: Rng + CryptoRng>(
+        vm: &VM<N, P>,
+        private_key: &PrivateKey<N>,
+        starting_supply: u64,
+        rng: &mut R,
+    ) -> Result<Self> {
+        Self::genesis_with_supply_and_timestamp(vm, private_key, starting_supply, N::GENESIS_TIMESTAMP, rng)
+    }
+
+    /// Initializes a new genesis block with the given timestamp, which mints the given starting supply to the caller.
+    fn genesis_with_supply_and_timestamp<P: ProgramStorage<N>, R: Rng + CryptoRng>(
+        vm: &VM<N, P>,
+        private_key: &PrivateKey<N>,
+        starting_supply: u64,
+        timestamp: i64,
+        rng: &mut R,
     ) -> Result<Self> {
         // Prepare the caller.
         let caller = Address::try_from(private_key)?;
@@ -31,7 +62,7 @@ impl<N: Network> Block<N> {
         // Prepare the function name.
         let function_name = FromStr::from_str("genesis")?;
         // Prepare the function inputs.
-        let inputs = [Value::from_str(&caller.to_string())?, Value::from_str("1_100_000_000_000_000_u64")?];
+        let inputs = [Value::from_str(&caller.to_string())?, Value::from_str(&format!("{starting_supply}u64"))?];
         // Authorize the call to start.
         let authorization = vm.authorize(private_key, &program_id, function_name, &inputs, rng)?;
         // Execute the genesis function.
@@ -40,25 +71,30 @@ impl<N: Network> Block<N> {
         // Prepare the transactions.
         let transactions = Transactions::from(&[transaction]);
         // Prepare the block header.
-        let header = Header::genesis(&transactions)?;
+        let header = Header::genesis_with_timestamp(&transactions, timestamp)?;
         // Prepare the previous block hash.
         let previous_hash = N::BlockHash::default();
 
         // Construct the block.
         let block = Self::new(private_key, previous_hash, header, transactions, rng)?;
         // Ensure the block is valid genesis block.
-        match block.is_genesis() {
+        match block.is_genesis_with_timestamp(timestamp) {
             true => Ok(block),
             false => bail!("Failed to initialize a genesis block"),
         }
     }
 
-    /// Returns `true` if the block is a genesis block.
+    /// Returns `true` if the block is a genesis block, with the network genesis timestamp.
     pub fn is_genesis(&self) -> bool {
+        self.is_genesis_with_timestamp(N::GENESIS_TIMESTAMP)
+    }
+
+    /// Returns `true` if the block is a genesis block, with the given genesis timestamp.
+    pub fn is_genesis_with_timestamp(&self, timestamp: i64) -> bool {
         // Ensure the previous block hash is zero.
         self.previous_hash == N::BlockHash::default()
             // Ensure the header is a genesis block header.
-            && self.header.is_genesis()
+            && self.header.is_genesis_with_timestamp(timestamp)
             // Ensure there is 1 transaction in the genesis block.
             && self.transactions.len() == 1
     }
@@ -66,6 +102,8 @@ impl<N: Network> Block<N> {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::ledger::{test_helpers::CurrentLedger, ProgramMemory, ProgramStore};
     use snarkvm_utilities::TestRng;
 
     #[test]
@@ -76,4 +114,33 @@ mod tests {
         // println!("{}", serde_json::to_string_pretty(&block).unwrap());
         assert!(block.is_genesis());
     }
+
+    #[test]
+    fn test_genesis_with_timestamp() {
+        let mut rng = TestRng::default();
+
+        // Sample the genesis private key and address.
+        let private_key = crate::ledger::test_helpers::sample_genesis_private_key(&mut rng);
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Create a genesis block with the given timestamp.
+        let timestamp = 1_667_260_800;
+        let vm = VM::new(ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap()).unwrap();
+        let block = Block::genesis_with_timestamp(&vm, &private_key, timestamp, &mut rng).unwrap();
+        assert!(block.is_genesis_with_timestamp(timestamp));
+        assert_eq!(block.timestamp(), timestamp);
+        assert_eq!(block.coinbase_target(), u64::MAX);
+        assert_eq!(block.proof_target(), u64::MAX);
+
+        // Ensure the block is not a genesis block on the default network.
+        assert!(!block.is_genesis());
+        assert!(!block.header().is_valid());
+        assert!(CurrentLedger::new_with_genesis(&block, address, None).is_err());
+
+        // Ensure a ledger can be initialized with the genesis block, when configured with its timestamp.
+        let ledger = CurrentLedger::new_with_genesis_and_timestamp(&block, address, timestamp, None).unwrap();
+        assert_eq!(ledger.latest_timestamp().unwrap(), timestamp);
+        // Ensure a ledger rejects the genesis block, when configured with a different timestamp.
+        assert!(CurrentLedger::new_with_genesis_and_timestamp(&block, address, timestamp + 1, None).is_err());
+    }
 }
@@ -19,23 +19,38 @@ use super::*;
 impl<N: Network> Header<N> {
     /// Initializes the genesis block header.
     pub fn genesis(transactions: &Transactions<N>) -> Result<Self> {
+        Self::genesis_with_timestamp(transactions, N::GENESIS_TIMESTAMP)
+    }
+
+    /// Initializes the genesis block header, with the given timestamp.
+    pub fn genesis_with_timestamp(transactions: &Transactions<N>, timestamp: i64) -> Result<Self> {
         // Prepare a genesis block header.
         let previous_state_root = Field::zero();
         let transactions_root = transactions.to_root()?;
-        let metadata = Metadata::genesis()?;
-
-        // Return the genesis block header.
-        Self::from(previous_state_root, transactions_root, metadata)
+        let metadata = Metadata::genesis_with_timestamp(timestamp)?;
+
+        // Construct the genesis block header directly, as `Self::from` only accepts the network genesis timestamp.
+        let header = Self { previous_state_root, transactions_root, metadata };
+        // Ensure the header is a genesis block header with the given timestamp.
+        match header.is_genesis_with_timestamp(timestamp) {
+            true => Ok(header),
+            false => bail!("Failed to initialize the genesis block header"),
+        }
     }
 
-    /// Returns `true` if the block header is a genesis block header.
+    /// Returns `true` if the block header is a genesis block header, with the network genesis timestamp.
     pub fn is_genesis(&self) -> bool {
+        self.is_genesis_with_timestamp(N::GENESIS_TIMESTAMP)
+    }
+
+    /// Returns `true` if the block header is a genesis block header, with the given genesis timestamp.
+    pub fn is_genesis_with_timestamp(&self, timestamp: i64) -> bool {
         // Ensure the previous ledger root is zero.
         self.previous_state_root == Field::zero()
             // Ensure the transactions root is nonzero.
             && self.transactions_root != Field::zero()
             // Ensure the metadata is a genesis metadata.
-            && self.metadata.is_genesis()
+            && self.metadata.is_genesis_with_timestamp(timestamp)
     }
 }
 
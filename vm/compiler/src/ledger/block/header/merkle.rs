@@ -56,6 +56,21 @@ impl<N: Network> Header<N> {
         }
     }
 
+    /// Returns `true` if the given leaf and path prove that the transactions root is in the block header with the given root.
+    pub fn verify_transactions_root_path(
+        header_root: &Field<N>,
+        transactions_root: &Field<N>,
+        leaf: &HeaderLeaf<N>,
+        path: &HeaderPath<N>,
+    ) -> bool {
+        // Ensure the leaf is the transactions root leaf.
+        if leaf.index() != 1 || leaf.id() != *transactions_root {
+            return false;
+        }
+        // Ensure the path is valid for the header root and leaf.
+        N::verify_merkle_path_bhp(path, header_root, &leaf.to_bits_le())
+    }
+
     /// Returns an instance of the Merkle tree for the block header.
     pub fn to_tree(&self) -> Result<HeaderTree<N>> {
         // Determine the number of leaves.
@@ -129,6 +144,8 @@ mod tests {
             let leaf = header.to_leaf(header.transactions_root())?;
             assert_eq!(leaf.index(), 1);
             check_path(header.to_path(&leaf)?, root, &leaf)?;
+            let path = header.to_path(&leaf)?;
+            assert!(Header::verify_transactions_root_path(&root, header.transactions_root(), &leaf, &path));
 
             // Check the 7th leaf.
             let leaf = header.to_leaf(&CurrentNetwork::hash_bhp512(&header.metadata().to_bits_le())?)?;
@@ -19,20 +19,39 @@ use super::*;
 impl<N: Network> Metadata<N> {
     /// Initializes the genesis metadata.
     pub fn genesis() -> Result<Self> {
+        Self::genesis_with_timestamp(N::GENESIS_TIMESTAMP)
+    }
+
+    /// Initializes the genesis metadata, with the given timestamp.
+    pub fn genesis_with_timestamp(timestamp: i64) -> Result<Self> {
         // Prepare a genesis metadata.
         let network = N::ID;
         let round = 0;
         let height = 0;
         let coinbase_target = u64::MAX;
         let proof_target = u64::MAX;
-        let timestamp = 0;
 
-        // Return the genesis metadata.
-        Self::new(network, round, height, coinbase_target, proof_target, timestamp)
+        // Ensure the genesis timestamp is non-negative.
+        if timestamp.is_negative() {
+            bail!("Invalid genesis timestamp: {timestamp}")
+        }
+
+        // Construct the genesis metadata directly, as `Self::new` only accepts the network genesis timestamp.
+        let metadata = Self { network, round, height, coinbase_target, proof_target, timestamp, _phantom: PhantomData };
+        // Ensure the metadata is a genesis metadata with the given timestamp.
+        match metadata.is_genesis_with_timestamp(timestamp) {
+            true => Ok(metadata),
+            false => bail!("Failed to initialize the genesis metadata"),
+        }
     }
 
-    /// Returns `true` if the metadata is a genesis metadata.
+    /// Returns `true` if the metadata is a genesis metadata, with the network genesis timestamp.
     pub fn is_genesis(&self) -> bool {
+        self.is_genesis_with_timestamp(N::GENESIS_TIMESTAMP)
+    }
+
+    /// Returns `true` if the metadata is a genesis metadata, with the given genesis timestamp.
+    pub fn is_genesis_with_timestamp(&self, timestamp: i64) -> bool {
         // Ensure the network ID is correct.
         self.network == N::ID
             // Ensure the round in the genesis block is 0.
@@ -43,8 +62,8 @@ impl<N: Network> Metadata<N> {
             && self.coinbase_target == u64::MAX
             // Ensure the proof target in the genesis block is u64::MAX.
             && self.proof_target == u64::MAX
-            // Ensure the timestamp in the genesis block is 0.
-            && self.timestamp == 0i64
+            // Ensure the timestamp in the genesis block is the given timestamp.
+            && self.timestamp == timestamp
     }
 }
 
@@ -93,4 +112,20 @@ mod tests {
         assert_eq!(metadata.proof_target(), u64::MAX);
         assert_eq!(metadata.timestamp(), 0);
     }
+
+    #[test]
+    fn test_genesis_metadata_with_timestamp() {
+        // Prepare a genesis metadata with a custom timestamp.
+        let timestamp = 1_667_260_800;
+        let metadata = Metadata::<CurrentNetwork>::genesis_with_timestamp(timestamp).unwrap();
+        assert!(metadata.is_genesis_with_timestamp(timestamp));
+
+        // Ensure the metadata is neither a genesis metadata nor valid on the default network.
+        assert!(!metadata.is_genesis());
+        assert!(!metadata.is_valid());
+        assert!(Metadata::<CurrentNetwork>::genesis().unwrap().is_genesis());
+
+        // Ensure a negative timestamp is rejected.
+        assert!(Metadata::<CurrentNetwork>::genesis_with_timestamp(-1).is_err());
+    }
 }
@@ -108,6 +108,11 @@ impl<N: Network> Block<N> {
     pub const fn signature(&self) -> &Signature<N> {
         &self.signature
     }
+
+    /// Returns the size of the block in bytes, in its serialized byte format.
+    pub fn size_in_bytes(&self) -> Result<usize> {
+        Ok(self.to_bytes_le()?.len())
+    }
 }
 
 impl<N: Network> Block<N> {
@@ -35,6 +35,14 @@ impl<N: Network> Transactions<N> {
         self.to_tree()?.prove(index, &leaf.to_bits_le())
     }
 
+    /// Returns `true` if each of the given Merkle paths proves the transaction ID at its index is in the given root.
+    pub fn verify_paths(root: &Field<N>, paths: &[(usize, N::TransactionID, TransactionsPath<N>)]) -> bool {
+        paths.iter().all(|(index, transaction_id, path)| {
+            // Ensure the Merkle path is for the given index, and the transaction ID is in the root.
+            *path.leaf_index() == *index as u64 && N::verify_merkle_path_bhp(path, root, &transaction_id.to_bits_le())
+        })
+    }
+
     /// The Merkle tree of transaction IDs for the block.
     pub fn to_tree(&self) -> Result<TransactionsTree<N>> {
         Self::transactions_tree(&self.transactions)
@@ -68,4 +76,41 @@ mod tests {
         // Ensure the log2 relationship between depth and the maximum number of transactions.
         assert_eq!(2usize.pow(TRANSACTIONS_DEPTH as u32), Transactions::<CurrentNetwork>::MAX_TRANSACTIONS);
     }
+    #[test]
+    fn test_verify_paths() {
+        let rng = &mut TestRng::default();
+
+        // Prepare the transactions.
+        let transactions = Transactions::<CurrentNetwork>::from(&[
+            crate::ledger::vm::test_helpers::sample_deployment_transaction(rng),
+            crate::ledger::vm::test_helpers::sample_execution_transaction(rng),
+        ]);
+        let root = transactions.to_root().unwrap();
+
+        // Prepare the Merkle path for each transaction.
+        let paths = transactions
+            .transaction_ids()
+            .enumerate()
+            .map(|(index, transaction_id)| {
+                (index, *transaction_id, transactions.to_path(index, **transaction_id).unwrap())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(paths.len(), 2);
+
+        // Ensure the paths are verified as a batch.
+        assert!(Transactions::verify_paths(&root, &paths));
+        assert!(Transactions::<CurrentNetwork>::verify_paths(&root, &[]));
+
+        // Ensure the paths are rejected for a different root.
+        assert!(!Transactions::verify_paths(&Field::from_u64(1), &paths));
+
+        // Ensure a path with a swapped index is rejected.
+        let mut swapped = paths.clone();
+        swapped[0].0 = 1;
+        assert!(!Transactions::verify_paths(&root, &swapped));
+        // Ensure a path with a swapped transaction ID is rejected.
+        let mut swapped = paths;
+        swapped[0].1 = swapped[1].1;
+        assert!(!Transactions::verify_paths(&root, &swapped));
+    }
 }
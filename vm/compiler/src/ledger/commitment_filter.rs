@@ -0,0 +1,206 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use console::{network::prelude::*, types::Field};
+
+use parking_lot::RwLock;
+use std::{borrow::Cow, marker::PhantomData, sync::Arc};
+
+/// The minimum number of bits in the filter.
+const MIN_NUM_BITS: usize = 1 << 16;
+/// The number of bits allocated for each commitment, when the filter is sized.
+const BITS_PER_COMMITMENT: usize = 32;
+/// The minimum number of bits for each commitment, below which the filter should be rebuilt.
+const MIN_BITS_PER_COMMITMENT: usize = 16;
+/// The number of bits set for each commitment.
+const NUM_HASHES: u64 = 4;
+
+/// A bloom filter of the commitments in the ledger, which is used to skip the storage lookup
+/// for commitments that do not exist. The filter has no false negatives, but may have false positives.
+///
+/// With `k` hashes and `b` bits for each commitment, the false-positive rate is approximately
+/// `(1 - e^(-k / b))^k`. The filter is sized for `BITS_PER_COMMITMENT` bits for each commitment,
+/// for a false-positive rate of about 0.02%. Once it holds fewer than `MIN_BITS_PER_COMMITMENT`
+/// bits for each commitment, the false-positive rate has risen to about 0.24%, and the filter
+/// should be rebuilt with a larger size.
+#[derive(Clone)]
+pub(super) struct CommitmentFilter<N: Network> {
+    /// The bits of the filter.
+    bits: Arc<RwLock<Bits>>,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+/// The bits of a commitment filter.
+struct Bits {
+    /// The words of the filter, each holding 64 bits.
+    words: Vec<u64>,
+    /// The number of commitments inserted into the filter.
+    num_commitments: usize,
+}
+
+impl Bits {
+    /// Initializes empty bits, sized for the given number of commitments.
+    fn new(num_commitments: usize) -> Self {
+        let num_bits = num_commitments.saturating_mul(BITS_PER_COMMITMENT).max(MIN_NUM_BITS).next_power_of_two();
+        Self { words: vec![0u64; num_bits / 64], num_commitments: 0 }
+    }
+
+    /// Returns the number of bits in the filter.
+    fn num_bits(&self) -> usize {
+        self.words.len() * 64
+    }
+}
+
+impl<N: Network> Default for CommitmentFilter<N> {
+    /// Initializes an empty filter.
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<N: Network> CommitmentFilter<N> {
+    /// Initializes an empty filter, sized for the given number of commitments.
+    pub(super) fn new(num_commitments: usize) -> Self {
+        Self { bits: Arc::new(RwLock::new(Bits::new(num_commitments))), _phantom: PhantomData }
+    }
+
+    /// Adds the given commitment to the filter.
+    pub(super) fn insert(&self, commitment: &Field<N>) {
+        Self::insert_into(&mut self.bits.write(), commitment);
+    }
+
+    /// Returns `false` if the given commitment is definitely not in the filter.
+    pub(super) fn may_contain(&self, commitment: &Field<N>) -> bool {
+        let bits = self.bits.read();
+        Self::bit_indices(commitment, bits.num_bits()).all(|index| bits.words[index / 64] & (1 << (index % 64)) != 0)
+    }
+
+    /// Returns `true` if the filter holds too many commitments for its size, and should be rebuilt.
+    pub(super) fn is_overloaded(&self) -> bool {
+        let bits = self.bits.read();
+        bits.num_commitments.saturating_mul(MIN_BITS_PER_COMMITMENT) > bits.num_bits()
+    }
+
+    /// Rebuilds the filter from the given commitments, sized for the given number of commitments.
+    pub(super) fn rebuild<'a>(&self, num_commitments: usize, commitments: impl Iterator<Item = Cow<'a, Field<N>>>)
+    where
+        N: 'a,
+    {
+        // Populate the new bits, before replacing the current bits.
+        let mut bits = Bits::new(num_commitments);
+        for commitment in commitments {
+            Self::insert_into(&mut bits, &commitment);
+        }
+        *self.bits.write() = bits;
+    }
+
+    /// Adds the given commitment to the given bits.
+    fn insert_into(bits: &mut Bits, commitment: &Field<N>) {
+        for index in Self::bit_indices(commitment, bits.num_bits()) {
+            bits.words[index / 64] |= 1 << (index % 64);
+        }
+        bits.num_commitments += 1;
+    }
+
+    /// Returns the indices of the bits for the given commitment, in a filter of the given size.
+    /// As commitments are hash outputs, their limbs are used directly to derive the indices.
+    fn bit_indices(commitment: &Field<N>, num_bits: usize) -> impl Iterator<Item = usize> {
+        let repr = commitment.to_repr();
+        let (first, second) = (repr.as_ref()[0], repr.as_ref()[1] | 1);
+        (0..NUM_HASHES).map(move |i| (first.wrapping_add(i.wrapping_mul(second)) % num_bits as u64) as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_commitment_filter() {
+        let rng = &mut TestRng::default();
+
+        // Sample the members and non-members.
+        let members = (0..1000).map(|_| Uniform::rand(rng)).collect::<Vec<Field<CurrentNetwork>>>();
+        let non_members = (0..1000).map(|_| Uniform::rand(rng)).collect::<Vec<Field<CurrentNetwork>>>();
+
+        let filter = CommitmentFilter::<CurrentNetwork>::default();
+        // Ensure an empty filter contains nothing.
+        assert!(members.iter().all(|commitment| !filter.may_contain(commitment)));
+
+        // Insert the members.
+        for commitment in &members {
+            filter.insert(commitment);
+        }
+
+        // Ensure there are no false negatives.
+        assert!(members.iter().all(|commitment| filter.may_contain(commitment)));
+        // Ensure the non-members are rejected, except for the rare false positive.
+        let num_false_positives = non_members.iter().filter(|commitment| filter.may_contain(commitment)).count();
+        assert!(num_false_positives < 10);
+    }
+
+    #[test]
+    fn test_commitment_filter_rebuild() {
+        let rng = &mut TestRng::default();
+
+        // Sample the members, up to the load factor of an empty filter.
+        let filter = CommitmentFilter::<CurrentNetwork>::default();
+        let capacity = MIN_NUM_BITS / MIN_BITS_PER_COMMITMENT;
+        let members = (0..capacity).map(|_| Uniform::rand(rng)).collect::<Vec<Field<CurrentNetwork>>>();
+        let non_members = (0..10_000).map(|_| Uniform::rand(rng)).collect::<Vec<Field<CurrentNetwork>>>();
+
+        // Insert the members.
+        for commitment in &members {
+            filter.insert(commitment);
+        }
+        assert!(!filter.is_overloaded());
+
+        // Ensure the false-positive rate at the load factor is near the expected 0.24%.
+        let num_false_positives = non_members.iter().filter(|commitment| filter.may_contain(commitment)).count();
+        assert!(num_false_positives < 50);
+
+        // Ensure the filter is overloaded after one more commitment.
+        let extra_member = Uniform::rand(rng);
+        filter.insert(&extra_member);
+        assert!(filter.is_overloaded());
+
+        // Rebuild the filter from all of the members.
+        let members = members.into_iter().chain([extra_member]).collect::<Vec<_>>();
+        filter.rebuild(members.len(), members.iter().map(Cow::Borrowed));
+        assert!(!filter.is_overloaded());
+        assert_eq!(filter.bits.read().num_commitments, members.len());
+        assert!(filter.bits.read().num_bits() >= members.len() * BITS_PER_COMMITMENT);
+
+        // Ensure there are no false negatives, and the false-positive rate is near the expected 0.02%.
+        assert!(members.iter().all(|commitment| filter.may_contain(commitment)));
+        let num_false_positives = non_members.iter().filter(|commitment| filter.may_contain(commitment)).count();
+        assert!(num_false_positives < 10);
+    }
+
+    #[test]
+    fn test_commitment_filter_sizing() {
+        // Ensure an empty filter has the minimum size.
+        assert_eq!(CommitmentFilter::<CurrentNetwork>::default().bits.read().num_bits(), MIN_NUM_BITS);
+        // Ensure a filter is sized for the given number of commitments.
+        let filter = CommitmentFilter::<CurrentNetwork>::new(100_000);
+        assert_eq!(filter.bits.read().num_bits(), (100_000 * BITS_PER_COMMITMENT).next_power_of_two());
+        assert!(!filter.is_overloaded());
+    }
+}
@@ -17,11 +17,9 @@
 use super::*;
 
 impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
-    /// Returns `true` if the given state root exists.
-    pub fn contains_state_root(&self, _state_root: &Field<N>) -> bool {
-        todo!()
-        // state_root == self.latest_state_root()
-        //     || self.headers.values().any(|h| Header::previous_state_root(&h) == state_root)
+    /// Returns `true` if the given state root is the latest state root, or a historical state root.
+    pub fn contains_state_root(&self, state_root: &N::StateRoot) -> Result<bool> {
+        Ok(self.state_roots.contains_key(state_root))
     }
 
     /// Returns `true` if the given block hash exists.
@@ -77,6 +75,10 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
 
     /// Returns `true` if the given commitment exists.
     pub fn contains_commitment(&self, commitment: &Field<N>) -> Result<bool> {
+        // If the commitment is not in the commitment filter, it does not exist.
+        if !self.commitment_filter.may_contain(commitment) {
+            return Ok(false);
+        }
         self.transitions.contains_commitment(commitment)
     }
 
@@ -39,12 +39,187 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
         self.transitions.find_transition_id(id)
     }
 
+    /// Returns the block that contains the given `commitment`.
+    pub fn find_block_for_commitment(&self, commitment: &Field<N>) -> Result<Block<N>> {
+        // Find the block hash that contains the commitment.
+        let (_, _, block_hash) = self.find_commitment_location(commitment)?;
+        // Retrieve the block.
+        match self.blocks.get_block(&block_hash)? {
+            Some(block) => Ok(block),
+            None => bail!("The block '{block_hash}' for commitment '{commitment}' is not in the ledger"),
+        }
+    }
+
+    /// Returns a view of the stores for finding records, which includes every block in the ledger.
+    pub(super) fn records_view(&self) -> RecordsView<'_, N, B> {
+        RecordsView::new(&self.blocks, &self.transactions, &self.transitions, None)
+    }
+
     /// Returns the record ciphertexts that belong to the given view key.
     pub fn find_record_ciphertexts<'a>(
         &'a self,
         view_key: &'a ViewKey<N>,
         filter: RecordsFilter<N>,
     ) -> Result<impl '_ + Iterator<Item = (Field<N>, Cow<'_, Record<N, Ciphertext<N>>>)>> {
+        self.records_view().find_record_ciphertexts(view_key, filter)
+    }
+
+    /// Returns the records that belong to the given view key.
+    pub fn find_records<'a>(
+        &'a self,
+        view_key: &'a ViewKey<N>,
+        filter: RecordsFilter<N>,
+    ) -> Result<impl '_ + Iterator<Item = (Field<N>, Record<N, Plaintext<N>>)>> {
+        self.records_view().find_records(view_key, filter)
+    }
+
+    /// Returns the records that belong to the given view key, and were created by the given program.
+    pub fn find_records_for_program<'a>(
+        &'a self,
+        view_key: &'a ViewKey<N>,
+        program_id: &ProgramID<N>,
+        filter: RecordsFilter<N>,
+    ) -> Result<impl '_ + Iterator<Item = (Field<N>, Record<N, Plaintext<N>>)>> {
+        let program_id = *program_id;
+        self.find_records(view_key, filter).map(|iter| {
+            iter.filter(move |(commitment, _)| {
+                // Retrieve the program ID of the transition that created the record.
+                let candidate = self
+                    .transitions
+                    .find_transition_id(commitment)
+                    .and_then(|transition_id| self.transitions.get_program_id(&transition_id));
+                match candidate {
+                    Ok(candidate) => candidate == Some(program_id),
+                    Err(e) => {
+                        warn!("Failed to find the program ID for record '{commitment}': {e}");
+                        false
+                    }
+                }
+            })
+        })
+    }
+
+    /// Returns at most `max_records` records that belong to the given view key,
+    /// along with a flag indicating whether more records are available.
+    #[allow(clippy::type_complexity)]
+    pub fn find_records_bounded(
+        &self,
+        view_key: &ViewKey<N>,
+        filter: RecordsFilter<N>,
+        max_records: usize,
+    ) -> Result<(Vec<(Field<N>, Record<N, Plaintext<N>>)>, bool)> {
+        let mut records = self.find_records(view_key, filter)?;
+        // Collect up to `max_records` records.
+        let bounded = records.by_ref().take(max_records).collect::<Vec<_>>();
+        // Determine if there are more records available.
+        let has_more = records.next().is_some();
+        Ok((bounded, has_more))
+    }
+}
+
+/// A view of the stores for finding records, which is shared by the ledger and its snapshots.
+/// If a maximum height is given, blocks above that height are treated as if they do not exist.
+pub(super) struct RecordsView<'a, N: Network, B: BlockStorage<N>> {
+    /// The block store.
+    blocks: &'a BlockStore<N, B>,
+    /// The transaction store.
+    transactions: &'a TransactionStore<N, B::TransactionStorage>,
+    /// The transition store.
+    transitions: &'a TransitionStore<N, B::TransitionStorage>,
+    /// The maximum visible block height, if any.
+    max_height: Option<u32>,
+}
+
+impl<'a, N: Network, B: BlockStorage<N>> RecordsView<'a, N, B> {
+    /// Initializes a new view of the given stores, up to the given maximum block height.
+    pub(super) const fn new(
+        blocks: &'a BlockStore<N, B>,
+        transactions: &'a TransactionStore<N, B::TransactionStorage>,
+        transitions: &'a TransitionStore<N, B::TransitionStorage>,
+        max_height: Option<u32>,
+    ) -> Self {
+        Self { blocks, transactions, transitions, max_height }
+    }
+
+    /// Returns `true` if the given serial number exists in the view.
+    pub(super) fn contains_serial_number(&self, serial_number: &Field<N>) -> Result<bool> {
+        match self.max_height {
+            None => self.transitions.contains_serial_number(serial_number),
+            Some(_) => Ok(self.transitions.contains_serial_number(serial_number)?
+                && self.find_transition_field_height(serial_number)?.is_some()),
+        }
+    }
+
+    /// Returns `true` if the given tag exists in the view.
+    pub(super) fn contains_tag(&self, tag: &Field<N>) -> Result<bool> {
+        match self.max_height {
+            None => self.transitions.contains_tag(tag),
+            Some(_) => match self.transitions.find_serial_number(tag)? {
+                Some(serial_number) => self.contains_serial_number(&serial_number),
+                None => Ok(false),
+            },
+        }
+    }
+
+    /// Returns `true` if the given commitment exists in the view.
+    pub(super) fn contains_commitment(&self, commitment: &Field<N>) -> Result<bool> {
+        match self.max_height {
+            None => self.transitions.contains_commitment(commitment),
+            Some(_) => Ok(self.transitions.contains_commitment(commitment)?
+                && self.find_transition_field_height(commitment)?.is_some()),
+        }
+    }
+
+    /// Returns the block height for the given block hash, if the block is in the view.
+    pub(super) fn find_block_height(&self, block_hash: &N::BlockHash) -> Result<Option<u32>> {
+        match (self.blocks.get_block_height(block_hash)?, self.max_height) {
+            (Some(height), Some(max_height)) if height > max_height => Ok(None),
+            (height, _) => Ok(height),
+        }
+    }
+
+    /// Returns the block height of the transition containing the given input ID or output ID,
+    /// if the transition is in the view.
+    fn find_transition_field_height(&self, id: &Field<N>) -> Result<Option<u32>> {
+        // Find the transition that contains the ID.
+        let transition_id = self.transitions.find_transition_id(id)?;
+        // Find the transaction that contains the transition.
+        let transaction_id = match self.transactions.find_transaction_id(&transition_id)? {
+            Some(transaction_id) => transaction_id,
+            None => return Ok(None),
+        };
+        // Find the block that contains the transaction.
+        match self.blocks.find_block_hash(&transaction_id)? {
+            Some(block_hash) => self.find_block_height(&block_hash),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns `true` if the record with the given commitment was created at or before the given height,
+    /// and was not spent at or before the given height.
+    fn is_unspent_at_height(&self, sk_tag: Field<N>, commitment: Field<N>, height: u32) -> Result<bool> {
+        // Ensure the record was created at or before the given height.
+        match self.find_transition_field_height(&commitment)? {
+            Some(created_height) if created_height <= height => (),
+            _ => return Ok(false),
+        }
+        // Determine if the record was spent at or before the given height.
+        let tag = Record::<N, Plaintext<N>>::tag(sk_tag, commitment)?;
+        match self.transitions.find_serial_number(&tag)? {
+            Some(serial_number) => match self.find_transition_field_height(&serial_number)? {
+                Some(spent_height) => Ok(spent_height > height),
+                None => Ok(true),
+            },
+            None => Ok(true),
+        }
+    }
+
+    /// Returns the record ciphertexts in the view that belong to the given view key.
+    pub(super) fn find_record_ciphertexts(
+        self,
+        view_key: &'a ViewKey<N>,
+        filter: RecordsFilter<N>,
+    ) -> Result<impl 'a + Iterator<Item = (Field<N>, Cow<'a, Record<N, Ciphertext<N>>>)>> {
         // Derive the address from the view key.
         let address = view_key.to_address();
         // Derive the `sk_tag` from the graph key.
@@ -53,56 +228,44 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
             Err(e) => bail!("Failed to derive the graph key from the view key: {e}"),
         };
 
-        Ok(self.records().flat_map(move |cow| {
-            // Retrieve the commitment and record.
-            let (commitment, record) = match cow {
-                (Cow::Borrowed(commitment), record) => (*commitment, record),
-                (Cow::Owned(commitment), record) => (commitment, record),
-            };
-
-            // Determine whether to decrypt this record (or not), based on the filter.
-            let commitment = match filter {
-                RecordsFilter::All => Ok(Some(commitment)),
-                RecordsFilter::Spent => Record::<N, Plaintext<N>>::tag(sk_tag, commitment).and_then(|tag| {
-                    // Determine if the record is spent.
-                    self.contains_tag(&tag).map(|is_spent| match is_spent {
-                        true => Some(commitment),
-                        false => None,
-                    })
-                }),
-                RecordsFilter::Unspent => Record::<N, Plaintext<N>>::tag(sk_tag, commitment).and_then(|tag| {
-                    // Determine if the record is spent.
-                    self.contains_tag(&tag).map(|is_spent| match is_spent {
-                        true => None,
-                        false => Some(commitment),
-                    })
-                }),
-                RecordsFilter::SlowSpent(private_key) => {
-                    Record::<N, Plaintext<N>>::serial_number(private_key, commitment).and_then(|serial_number| {
-                        // Determine if the record is spent.
-                        self.contains_serial_number(&serial_number).map(|is_spent| match is_spent {
-                            true => Some(commitment),
-                            false => None,
-                        })
-                    })
-                }
-                RecordsFilter::SlowUnspent(private_key) => {
-                    Record::<N, Plaintext<N>>::serial_number(private_key, commitment).and_then(|serial_number| {
-                        // Determine if the record is spent.
-                        self.contains_serial_number(&serial_number).map(|is_spent| match is_spent {
-                            true => None,
-                            false => Some(commitment),
-                        })
-                    })
-                }
-            };
+        let transitions = self.transitions;
+        Ok(transitions.records().flat_map(move |(commitment, record)| {
+            let commitment = commitment.into_owned();
 
-            match commitment {
-                Ok(Some(commitment)) => match record.is_owner(&address, view_key) {
+            // Determine whether to decrypt this record (or not), based on the view and the filter.
+            let is_selected = match self.max_height {
+                None => Ok(true),
+                Some(_) => self.contains_commitment(&commitment),
+            }
+            .and_then(|is_visible| match is_visible {
+                true => match filter {
+                    RecordsFilter::All => Ok(true),
+                    RecordsFilter::Spent => {
+                        Record::<N, Plaintext<N>>::tag(sk_tag, commitment).and_then(|tag| self.contains_tag(&tag))
+                    }
+                    RecordsFilter::Unspent => Record::<N, Plaintext<N>>::tag(sk_tag, commitment)
+                        .and_then(|tag| self.contains_tag(&tag))
+                        .map(|is_spent| !is_spent),
+                    RecordsFilter::SlowSpent(private_key) => {
+                        Record::<N, Plaintext<N>>::serial_number(private_key, commitment)
+                            .and_then(|serial_number| self.contains_serial_number(&serial_number))
+                    }
+                    RecordsFilter::SlowUnspent(private_key) => {
+                        Record::<N, Plaintext<N>>::serial_number(private_key, commitment)
+                            .and_then(|serial_number| self.contains_serial_number(&serial_number))
+                            .map(|is_spent| !is_spent)
+                    }
+                    RecordsFilter::UnspentAtHeight(height) => self.is_unspent_at_height(sk_tag, commitment, height),
+                },
+                false => Ok(false),
+            });
+
+            match is_selected {
+                Ok(true) => match record.is_owner(&address, view_key) {
                     true => Some((commitment, record)),
                     false => None,
                 },
-                Ok(None) => None,
+                Ok(false) => None,
                 Err(e) => {
                     warn!("Failed to process 'find_record_ciphertexts({:?})': {e}", filter);
                     None
@@ -111,12 +274,12 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
         }))
     }
 
-    /// Returns the records that belong to the given view key.
-    pub fn find_records<'a>(
-        &'a self,
+    /// Returns the records in the view that belong to the given view key.
+    pub(super) fn find_records(
+        self,
         view_key: &'a ViewKey<N>,
         filter: RecordsFilter<N>,
-    ) -> Result<impl '_ + Iterator<Item = (Field<N>, Record<N, Plaintext<N>>)>> {
+    ) -> Result<impl 'a + Iterator<Item = (Field<N>, Record<N, Plaintext<N>>)>> {
         self.find_record_ciphertexts(view_key, filter).map(|iter| {
             iter.flat_map(|(commitment, record)| match record.decrypt(view_key) {
                 Ok(record) => Some((commitment, record)),
@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The parameters for launching a new network.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GenesisConfig<N: Network> {
+    /// The initial validators, in addition to the signer of the genesis block.
+    pub validators: Vec<Address<N>>,
+    /// The starting supply, which is minted to the signer of the genesis block.
+    pub starting_supply: u64,
+}
+
+impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
+    /// Initializes a new instance of `Ledger` with a genesis block constructed from the given config.
+    /// The genesis block is signed by the given private key, which is added as the first validator.
+    ///
+    /// Note: The coinbase target is not configurable, as a genesis block requires a coinbase target of `u64::MAX`.
+    pub fn initialize_from_config<R: Rng + CryptoRng>(
+        config: GenesisConfig<N>,
+        private_key: &PrivateKey<N>,
+        dev: Option<u16>,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Ensure the starting supply is nonzero.
+        if config.starting_supply == 0 {
+            bail!("The starting supply of the genesis block must be nonzero")
+        }
+
+        // Construct the genesis block, using a temporary VM.
+        let genesis = {
+            let vm = VM::new(ProgramStore::<N, ProgramMemory<N>>::open(None)?)?;
+            Block::genesis_with_supply(&vm, private_key, config.starting_supply, rng)?
+        };
+
+        // Initialize the ledger, with the genesis signer as the first validator.
+        let address = Address::try_from(private_key)?;
+        let mut ledger = Self::new_with_genesis(&genesis, address, dev)?;
+
+        // Add the remaining validators.
+        for validator in config.validators {
+            if !ledger.validators.contains_key(&validator) {
+                ledger.add_validator(validator)?;
+            }
+        }
+
+        // Return the ledger.
+        Ok(ledger)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::test_helpers::CurrentLedger;
+    use console::network::Testnet3;
+    use snarkvm_utilities::TestRng;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_initialize_from_config() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key, view key, and address.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        // Sample an additional validator.
+        let validator = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        // Initialize the config.
+        let config = GenesisConfig { validators: vec![address, validator], starting_supply: 1_000_000 };
+
+        // Launch the ledger.
+        let ledger = CurrentLedger::initialize_from_config(config, &private_key, None, rng).unwrap();
+
+        // Ensure the genesis block is valid, and signed by the given private key.
+        let genesis = ledger.genesis_block().unwrap();
+        assert!(genesis.is_genesis());
+        assert_eq!(genesis.signature().to_address(), address);
+        assert_eq!(ledger.latest_height(), 0);
+
+        // Ensure the validators are set, without duplicates.
+        assert_eq!(ledger.validators().keys().copied().collect::<Vec<_>>(), vec![address, validator]);
+
+        // Ensure the starting supply is minted to the genesis signer.
+        let records = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().collect::<Vec<_>>();
+        assert_eq!(records.len(), 1);
+        assert_eq!(***records[0].1.gates(), 1_000_000);
+
+        // Ensure a zero starting supply is rejected.
+        let config = GenesisConfig { validators: vec![], starting_supply: 0 };
+        assert!(CurrentLedger::initialize_from_config(config, &private_key, None, rng).is_err());
+    }
+}
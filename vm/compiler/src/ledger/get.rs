@@ -31,6 +31,12 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
         }
     }
 
+    /// Returns the genesis block.
+    pub fn genesis_block(&self) -> Result<Block<N>> {
+        // The genesis block is immutable, so it is cached after the first load.
+        self.genesis.get_or_try_init(|| self.get_block(0)).cloned()
+    }
+
     /// Returns the block hash for the given block height.
     pub fn get_hash(&self, height: u32) -> Result<N::BlockHash> {
         match self.blocks.get_block_hash(height)? {
@@ -61,6 +67,18 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
         }
     }
 
+    /// Returns the header leaf and header path, which prove the transactions root is in the block header
+    /// for the given block height.
+    pub fn header_transactions_root_proof(&self, height: u32) -> Result<(HeaderLeaf<N>, HeaderPath<N>)> {
+        // Retrieve the block header.
+        let header = self.get_header(height)?;
+        // Construct the header leaf for the transactions root.
+        let header_leaf = header.to_leaf(header.transactions_root())?;
+        // Construct the header path.
+        let header_path = header.to_path(&header_leaf)?;
+        Ok((header_leaf, header_path))
+    }
+
     /// Returns the block transactions for the given block height.
     pub fn get_transactions(&self, height: u32) -> Result<Transactions<N>> {
         // Retrieve the block hash.
@@ -84,6 +102,32 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
         }
     }
 
+    /// Returns the transitions for the given transaction ID, including the additional fee transition (if any).
+    pub fn get_transitions_for_transaction(&self, transaction_id: &N::TransactionID) -> Result<Vec<Transition<N>>> {
+        Ok(self.get_transaction(*transaction_id)?.into_transitions().collect())
+    }
+
+    /// Returns the status of the transaction for the given transaction id.
+    pub fn transaction_status(&self, transaction_id: &N::TransactionID) -> Result<TxStatus<N>> {
+        // Check if the transaction is in the ledger.
+        if let Some(block_hash) = self.blocks.find_block_hash(transaction_id)? {
+            // Retrieve the block height.
+            let height = match self.blocks.get_block_height(&block_hash)? {
+                Some(height) => height,
+                None => bail!("Missing block height for block '{block_hash}'"),
+            };
+            // Compute the number of confirmations.
+            let confirmations = self.current_height.saturating_sub(height).saturating_add(1);
+            return Ok(TxStatus::Confirmed { block_hash, height, confirmations });
+        }
+
+        // Check if the transaction is in the memory pool.
+        match self.memory_pool.contains_key(transaction_id) {
+            true => Ok(TxStatus::Pending),
+            false => Ok(TxStatus::Unknown),
+        }
+    }
+
     /// Returns the program for the given program id.
     pub fn get_program(&self, program_id: ProgramID<N>) -> Result<Program<N>> {
         match self.transactions.get_program(&program_id)? {
@@ -92,6 +136,59 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
         }
     }
 
+    /// Returns the block height and the transaction that deployed the given program ID.
+    pub fn get_deployment_transaction(&self, program_id: &ProgramID<N>) -> Result<Option<(u32, Transaction<N>)>> {
+        // Retrieve the deployment transaction ID.
+        let transaction_id = match self.transactions.find_deployment_id(program_id)? {
+            Some(transaction_id) => transaction_id,
+            None => return Ok(None),
+        };
+        // Retrieve the block hash.
+        let block_hash = match self.blocks.find_block_hash(&transaction_id)? {
+            Some(block_hash) => block_hash,
+            None => bail!("Missing block hash for deployment transaction '{transaction_id}'"),
+        };
+        // Retrieve the block height.
+        let height = match self.blocks.get_block_height(&block_hash)? {
+            Some(height) => height,
+            None => bail!("Missing block height for block '{block_hash}'"),
+        };
+        // Retrieve the transaction.
+        Ok(Some((height, self.get_transaction(transaction_id)?)))
+    }
+
+    /// Returns the block height and block hash of the block that spent the given serial number.
+    pub fn get_serial_number_block(&self, serial_number: &Field<N>) -> Result<Option<(u32, N::BlockHash)>> {
+        // Ensure the serial number exists.
+        if !self.contains_serial_number(serial_number)? {
+            return Ok(None);
+        }
+        // Retrieve the block that contains the serial number.
+        self.get_input_or_output_block(serial_number).map(Some)
+    }
+
+    /// Returns the block height and block hash of the block that contains the given input ID or output ID.
+    pub(super) fn get_input_or_output_block(&self, id: &Field<N>) -> Result<(u32, N::BlockHash)> {
+        // Find the transition that contains the ID.
+        let transition_id = self.transitions.find_transition_id(id)?;
+        // Find the transaction that contains the transition.
+        let transaction_id = match self.transactions.find_transaction_id(&transition_id)? {
+            Some(transaction_id) => transaction_id,
+            None => bail!("The transaction ID for '{id}' is not in the ledger"),
+        };
+        // Find the block that contains the transaction.
+        let block_hash = match self.blocks.find_block_hash(&transaction_id)? {
+            Some(block_hash) => block_hash,
+            None => bail!("The block hash for '{id}' is not in the ledger"),
+        };
+        // Retrieve the block height.
+        let height = match self.blocks.get_block_height(&block_hash)? {
+            Some(height) => height,
+            None => bail!("Missing block height for block '{block_hash}'"),
+        };
+        Ok((height, block_hash))
+    }
+
     /// Returns the block signature for the given block height.
     pub fn get_signature(&self, height: u32) -> Result<Signature<N>> {
         // Retrieve the block hash.
@@ -110,8 +207,9 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ledger::test_helpers::CurrentLedger;
-    use console::network::Testnet3;
+    use crate::ledger::{test_helpers, test_helpers::CurrentLedger};
+    use console::{network::Testnet3, program::Value};
+    use snarkvm_utilities::TestRng;
 
     type CurrentNetwork = Testnet3;
 
@@ -127,4 +225,109 @@ mod tests {
         // Ensure the genesis block matches.
         assert_eq!(genesis, candidate);
     }
+
+    #[test]
+    fn test_get_transitions_for_transaction() {
+        // Initialize a new ledger.
+        let ledger = CurrentLedger::new(None).unwrap();
+        // Retrieve the genesis transaction.
+        let transaction = ledger.get_block(0).unwrap().transactions().values().next().unwrap().clone();
+
+        // Ensure the transitions are returned.
+        let transitions = ledger.get_transitions_for_transaction(&transaction.id()).unwrap();
+        assert!(!transitions.is_empty());
+        assert_eq!(transitions, transaction.transitions().cloned().collect::<Vec<_>>());
+
+        // Ensure a missing transaction is rejected.
+        assert!(ledger.get_transitions_for_transaction(&Default::default()).is_err());
+    }
+
+    #[test]
+    fn test_header_transactions_root_proof() {
+        // Initialize a new ledger.
+        let ledger = CurrentLedger::new(None).unwrap();
+        // Retrieve the genesis block header.
+        let header = ledger.get_header(0).unwrap();
+        let header_root = header.to_root().unwrap();
+
+        // Retrieve the proof for the genesis block.
+        let (header_leaf, header_path) = ledger.header_transactions_root_proof(0).unwrap();
+        // Ensure the proof is valid.
+        assert!(Header::verify_transactions_root_path(
+            &header_root,
+            header.transactions_root(),
+            &header_leaf,
+            &header_path
+        ));
+
+        // Ensure the proof is invalid for a different transactions root.
+        assert!(!Header::verify_transactions_root_path(
+            &header_root,
+            header.previous_state_root(),
+            &header_leaf,
+            &header_path
+        ));
+        // Ensure the proof is invalid for a different header root.
+        assert!(!Header::verify_transactions_root_path(
+            header.transactions_root(),
+            header.transactions_root(),
+            &header_leaf,
+            &header_path
+        ));
+    }
+
+    #[test]
+    fn test_genesis_block() {
+        // Initialize a new ledger.
+        let ledger = CurrentLedger::new(None).unwrap();
+        // Retrieve the genesis block.
+        let genesis = ledger.genesis_block().unwrap();
+        // Ensure the genesis block matches.
+        assert_eq!(genesis, ledger.get_block(0).unwrap());
+
+        // Ensure the genesis block is cached.
+        let cached = ledger.genesis.get().unwrap();
+        assert_eq!(&genesis, cached);
+        // Ensure repeated calls return the cached instance.
+        assert_eq!(genesis, ledger.genesis_block().unwrap());
+        assert!(core::ptr::eq(cached, ledger.genesis.get().unwrap()));
+    }
+
+    #[test]
+    fn test_get_serial_number_block() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Fetch the genesis record.
+        let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+
+        // Spend the genesis record.
+        let transaction = Transaction::execute(
+            ledger.vm(),
+            &private_key,
+            &ProgramID::from_str("credits.aleo").unwrap(),
+            Identifier::from_str("split").unwrap(),
+            &[Value::Record(record), Value::from_str("1u64").unwrap()],
+            None,
+            rng,
+        )
+        .unwrap();
+        let serial_number = *transaction.serial_numbers().next().unwrap();
+
+        // Ensure the serial number is not yet spent.
+        assert_eq!(ledger.get_serial_number_block(&serial_number).unwrap(), None);
+
+        // Add the transaction in the next block.
+        ledger.add_to_memory_pool(transaction).unwrap();
+        let next_block = ledger.propose_next_block(&private_key, rng).unwrap();
+        ledger.add_next_block(&next_block).unwrap();
+
+        // Ensure the serial number resolves to the block that spent it.
+        assert_eq!(ledger.get_serial_number_block(&serial_number).unwrap(), Some((1, next_block.hash())));
+    }
 }
@@ -17,6 +17,13 @@
 use super::*;
 
 impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
+    /* Block */
+
+    /// Returns an iterator over the blocks, from genesis to the latest block, in ascending height order.
+    pub fn blocks(&self) -> impl '_ + Iterator<Item = Result<Block<N>>> {
+        (0..=self.current_height).map(move |height| self.get_block(height))
+    }
+
     /* Transaction */
 
     /// Returns an iterator over the transaction IDs, for all transactions in `self`.
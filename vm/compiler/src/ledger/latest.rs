@@ -57,8 +57,209 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
         Ok(self.get_header(self.current_height)?.timestamp())
     }
 
+    /// Returns the number of seconds that have elapsed since the latest block timestamp.
+    pub fn seconds_since_last_block(&self) -> Result<i64> {
+        Ok(OffsetDateTime::now_utc().unix_timestamp().saturating_sub(self.latest_timestamp()?))
+    }
+
+    /// Returns the median timestamp of the latest `N::NUM_MEDIAN_TIMESTAMP_BLOCKS` blocks.
+    pub fn latest_median_timestamp(&self) -> Result<i64> {
+        // Determine the starting height of the recent blocks.
+        let start_height = self.current_height.saturating_sub(N::NUM_MEDIAN_TIMESTAMP_BLOCKS.saturating_sub(1));
+        // Retrieve the timestamps of the recent blocks.
+        let mut timestamps = (start_height..=self.current_height)
+            .map(|height| Ok(self.get_header(height)?.timestamp()))
+            .collect::<Result<Vec<_>>>()?;
+        // Sort the timestamps.
+        timestamps.sort_unstable();
+        // Return the median timestamp.
+        Ok(timestamps[timestamps.len() / 2])
+    }
+
+    /// Returns the average number of seconds between blocks, over the latest `window` blocks.
+    /// If the ledger contains fewer than `window` blocks after the genesis block, every block is used.
+    pub fn average_block_time(&self, window: u32) -> Result<f64> {
+        // Ensure the window is nonzero.
+        ensure!(window > 0, "The window for the average block time must be nonzero");
+        // Ensure there is at least one block after the genesis block.
+        ensure!(self.current_height > 0, "The average block time requires at least one block after genesis");
+
+        // Determine the starting height of the window.
+        let start_height = self.current_height.saturating_sub(window);
+        // Compute the elapsed time over the window.
+        let elapsed = self.latest_timestamp()?.saturating_sub(self.get_header(start_height)?.timestamp());
+        // Return the average block time.
+        Ok(elapsed as f64 / f64::from(self.current_height - start_height))
+    }
+
+    /// Returns the coinbase targets of the latest `window` blocks, in order of ascending height.
+    pub fn latest_coinbase_target_history(&self, window: u32) -> Result<Vec<u64>> {
+        // If the window is empty, return an empty history.
+        if window == 0 {
+            return Ok(vec![]);
+        }
+        // Determine the starting height of the window.
+        let start_height = self.current_height.saturating_sub(window - 1);
+        // Retrieve the coinbase targets.
+        (start_height..=self.current_height).map(|height| Ok(self.get_header(height)?.coinbase_target())).collect()
+    }
+
+    /// Returns the block locators for the latest block, as `(height, hash)` pairs.
+    /// The locators are spaced exponentially back from the tip, and always end with the genesis block.
+    pub fn latest_block_locators(&self) -> Result<Vec<(u32, N::BlockHash)>> {
+        block_locator_heights(self.current_height)
+            .into_iter()
+            .map(|height| Ok((height, self.get_hash(height)?)))
+            .collect()
+    }
+
     /// Returns the latest block transactions.
     pub fn latest_transactions(&self) -> Result<Transactions<N>> {
         self.get_transactions(self.current_height)
     }
 }
+
+/// Returns the block locator heights for the given latest height, in descending order.
+/// The heights are `latest`, `latest - 1`, `latest - 2`, `latest - 4`, `latest - 8`, ..., and end with `0`.
+fn block_locator_heights(latest_height: u32) -> Vec<u32> {
+    // Start with the latest height.
+    let mut heights = vec![latest_height];
+    // Add the exponentially-spaced heights.
+    let mut offset = 1u32;
+    while offset < latest_height {
+        heights.push(latest_height - offset);
+        offset = match offset.checked_mul(2) {
+            Some(offset) => offset,
+            None => break,
+        };
+    }
+    // Ensure the genesis height is the last entry.
+    if latest_height > 0 {
+        heights.push(0);
+    }
+    heights
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::test_helpers::CurrentLedger;
+    use console::{network::Testnet3, program::Value};
+    use snarkvm_utilities::TestRng;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_block_locator_heights() {
+        assert_eq!(block_locator_heights(0), vec![0]);
+        assert_eq!(block_locator_heights(1), vec![1, 0]);
+        assert_eq!(block_locator_heights(2), vec![2, 1, 0]);
+        assert_eq!(block_locator_heights(20), vec![20, 19, 18, 16, 12, 4, 0]);
+
+        for latest_height in 0..1000 {
+            let heights = block_locator_heights(latest_height);
+            // Ensure the tip is the first entry, and genesis is the last entry.
+            assert_eq!(heights.first(), Some(&latest_height));
+            assert_eq!(heights.last(), Some(&0));
+            // Ensure the heights are strictly descending.
+            assert!(heights.windows(2).all(|pair| pair[0] > pair[1]));
+        }
+    }
+
+    #[test]
+    fn test_latest_timestamp() {
+        // Initialize a new ledger.
+        let ledger = CurrentLedger::new(None).unwrap();
+        // Retrieve the genesis block.
+        let genesis = ledger.get_block(0).unwrap();
+
+        // Ensure the latest timestamp is the genesis timestamp.
+        assert_eq!(ledger.latest_timestamp().unwrap(), genesis.header().timestamp());
+        // Ensure the elapsed time is measured from the genesis timestamp.
+        let elapsed = ledger.seconds_since_last_block().unwrap();
+        let expected = OffsetDateTime::now_utc().unix_timestamp() - genesis.timestamp();
+        assert!(elapsed <= expected && expected - elapsed <= 1);
+    }
+
+    #[test]
+    fn test_latest_block_locators() {
+        // Initialize a new ledger.
+        let ledger = CurrentLedger::new(None).unwrap();
+        // Retrieve the block locators.
+        let locators = ledger.latest_block_locators().unwrap();
+        // Ensure the genesis block is the only entry.
+        assert_eq!(locators, vec![(0, ledger.get_hash(0).unwrap())]);
+    }
+    #[test]
+    fn test_average_block_time() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key, view key, and address.
+        let private_key = crate::ledger::test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Initialize a ledger with a genesis block from 100 seconds ago.
+        let genesis_timestamp = OffsetDateTime::now_utc().unix_timestamp() - 100;
+        let vm = VM::new(ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap()).unwrap();
+        let genesis = Block::genesis_with_timestamp(&vm, &private_key, genesis_timestamp, rng).unwrap();
+        let mut ledger =
+            CurrentLedger::new_with_genesis_and_timestamp(&genesis, address, genesis_timestamp, None).unwrap();
+
+        // Ensure the average block time requires a block after genesis.
+        assert!(ledger.average_block_time(1).is_err());
+
+        // Add 3 blocks, which are spaced 20 seconds apart.
+        for i in 1..=3 {
+            // Split the largest record, to include a transaction in the block.
+            let (_, record) = ledger
+                .find_records(&view_key, RecordsFilter::Unspent)
+                .unwrap()
+                .max_by_key(|(_, record)| ***record.gates())
+                .unwrap();
+            let transaction = Transaction::execute(
+                ledger.vm(),
+                &private_key,
+                &ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str("split").unwrap(),
+                &[Value::Record(record), Value::from_str("1u64").unwrap()],
+                None,
+                rng,
+            )
+            .unwrap();
+            let proposed_block = ledger.propose_next_block_with(&private_key, vec![transaction], rng).unwrap();
+
+            // Reconstruct the proposed block with the given timestamp.
+            let metadata = Metadata::new(
+                CurrentNetwork::ID,
+                proposed_block.round(),
+                proposed_block.height(),
+                proposed_block.coinbase_target(),
+                proposed_block.proof_target(),
+                genesis_timestamp + 20 * i,
+            )
+            .unwrap();
+            let header =
+                Header::from(*proposed_block.previous_state_root(), *proposed_block.transactions_root(), metadata)
+                    .unwrap();
+            let transactions = proposed_block.transactions().clone();
+            let block = Block::new(&private_key, proposed_block.previous_hash(), header, transactions, rng).unwrap();
+            ledger.add_next_block(&block).unwrap();
+        }
+
+        // Ensure the average block time matches the spacing.
+        assert_eq!(ledger.average_block_time(1).unwrap(), 20.0);
+        assert_eq!(ledger.average_block_time(3).unwrap(), 20.0);
+        // Ensure a window larger than the chain uses every block.
+        assert_eq!(ledger.average_block_time(10).unwrap(), 20.0);
+        // Ensure a zero window is rejected.
+        assert!(ledger.average_block_time(0).is_err());
+
+        // Ensure the coinbase target history is returned for the window.
+        let history = ledger.latest_coinbase_target_history(2).unwrap();
+        let expected = vec![ledger.get_header(2).unwrap().coinbase_target(), ledger.latest_coinbase_target().unwrap()];
+        assert_eq!(history, expected);
+        assert_eq!(ledger.latest_coinbase_target_history(10).unwrap().len(), 4);
+        assert!(ledger.latest_coinbase_target_history(0).unwrap().is_empty());
+    }
+}
@@ -35,6 +35,18 @@ pub use transition::*;
 mod vm;
 pub use vm::*;
 
+mod commitment_filter;
+use commitment_filter::CommitmentFilter;
+
+mod verification_cache;
+use verification_cache::VerificationCache;
+
+mod genesis;
+pub use genesis::*;
+
+mod snapshot;
+pub use snapshot::*;
+
 mod contains;
 mod find;
 mod get;
@@ -52,7 +64,12 @@ use console::{
 
 use anyhow::Result;
 use indexmap::IndexMap;
-use std::borrow::Cow;
+use once_cell::sync::OnceCell;
+use std::{
+    borrow::Cow,
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
 use time::OffsetDateTime;
 
 #[cfg(feature = "parallel")]
@@ -60,11 +77,15 @@ use rayon::prelude::*;
 
 /// The depth of the Merkle tree for the blocks.
 const BLOCKS_DEPTH: u8 = 32;
+/// The number of recently-added blocks used to measure the block application rate.
+const NUM_RECENT_BLOCK_TIMES: usize = 100;
 
 /// The Merkle tree for the block state.
 pub type BlockTree<N> = BHPMerkleTree<N, BLOCKS_DEPTH>;
 /// The Merkle path for the state tree blocks.
 pub type BlockPath<N> = MerklePath<N, BLOCKS_DEPTH>;
+/// The block header root, header leaf, header path, and block path for a block.
+type BlockPaths<N> = (Field<N>, HeaderLeaf<N>, HeaderPath<N>, BlockPath<N>);
 
 #[derive(Copy, Clone, Debug)]
 pub enum RecordsFilter<N: Network> {
@@ -78,8 +99,95 @@ pub enum RecordsFilter<N: Network> {
     SlowSpent(PrivateKey<N>),
     /// Returns all records associated with the account that are **not spent** with the given private key.
     SlowUnspent(PrivateKey<N>),
+    /// Returns only records associated with the account that were created and **not spent** as of the given height.
+    /// Note: This is slower than `Unspent`, as it looks up the blocks that created and spent each record.
+    UnspentAtHeight(u32),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TxStatus<N: Network> {
+    /// The transaction is in the memory pool, and has not been included in a block.
+    Pending,
+    /// The transaction is included in the block with the given hash and height.
+    Confirmed {
+        /// The hash of the block containing the transaction.
+        block_hash: N::BlockHash,
+        /// The height of the block containing the transaction.
+        height: u32,
+        /// The number of blocks from the latest block to the containing block (inclusive).
+        confirmations: u32,
+    },
+    /// The transaction is not in the memory pool or the ledger.
+    Unknown,
+}
+
+/// A reason for which a transaction is rejected by the ledger.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransactionError<N: Network> {
+    /// The transaction failed verification in the VM.
+    Invalid(N::TransactionID),
+    /// The transaction ID already exists in the ledger.
+    DuplicateTransactionID(N::TransactionID),
+    /// The input ID already exists in the ledger.
+    DuplicateInputID(Field<N>),
+    /// The serial number already exists in the ledger.
+    DuplicateSerialNumber(Field<N>),
+    /// The tag already exists in the ledger.
+    DuplicateTag(Field<N>),
+    /// The commitment referenced by an origin does not exist in the ledger.
+    UnknownCommitment(Field<N>),
+    /// The origin references a state root, which is not supported.
+    UnsupportedStateRoot,
+    /// The output ID already exists in the ledger.
+    DuplicateOutputID(Field<N>),
+    /// The commitment already exists in the ledger.
+    DuplicateCommitment(Field<N>),
+    /// The nonce already exists in the ledger.
+    DuplicateNonce(Group<N>),
+    /// The deployed program ID already exists in the ledger.
+    DuplicateProgramID(ProgramID<N>),
+    /// The transition public key already exists in the ledger.
+    DuplicateTransitionPublicKey(Group<N>),
+    /// The transition commitment already exists in the ledger.
+    DuplicateTransitionCommitment(Field<N>),
+    /// The ledger storage failed to perform a lookup.
+    Storage(String),
+}
+
+impl<N: Network> Display for TransactionError<N> {
+    /// Prints the reason for rejecting the transaction.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Invalid(id) => write!(f, "Transaction '{id}' is invalid"),
+            Self::DuplicateTransactionID(id) => write!(f, "Transaction '{id}' already exists in the ledger"),
+            Self::DuplicateInputID(id) => write!(f, "Input ID '{id}' already exists in the ledger"),
+            Self::DuplicateSerialNumber(serial_number) => {
+                write!(f, "Serial number '{serial_number}' already exists in the ledger")
+            }
+            Self::DuplicateTag(tag) => write!(f, "Tag '{tag}' already exists in the ledger"),
+            Self::UnknownCommitment(commitment) => {
+                write!(f, "The given transaction references a non-existent commitment {commitment}")
+            }
+            Self::UnsupportedStateRoot => write!(f, "State roots are currently not supported (yet)"),
+            Self::DuplicateOutputID(id) => write!(f, "Output ID '{id}' already exists in the ledger"),
+            Self::DuplicateCommitment(commitment) => {
+                write!(f, "Commitment '{commitment}' already exists in the ledger")
+            }
+            Self::DuplicateNonce(nonce) => write!(f, "Nonce '{nonce}' already exists in the ledger"),
+            Self::DuplicateProgramID(program_id) => write!(f, "Program ID '{program_id}' already exists in the ledger"),
+            Self::DuplicateTransitionPublicKey(tpk) => {
+                write!(f, "Transition public key '{tpk}' already exists in the ledger")
+            }
+            Self::DuplicateTransitionCommitment(tcm) => {
+                write!(f, "Transition commitment '{tcm}' already exists in the ledger")
+            }
+            Self::Storage(error) => write!(f, "{error}"),
+        }
+    }
 }
 
+impl<N: Network> std::error::Error for TransactionError<N> {}
+
 #[derive(Clone)]
 pub struct Ledger<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> {
     /// The current block hash.
@@ -90,6 +198,8 @@ pub struct Ledger<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> {
     current_round: u64,
     /// The current block tree.
     block_tree: BlockTree<N>,
+    /// The mapping of each historical state root to the block height at which it was the latest state root.
+    state_roots: IndexMap<N::StateRoot, u32>,
     /// The block store.
     blocks: BlockStore<N, B>,
     /// The transaction store.
@@ -97,10 +207,19 @@ pub struct Ledger<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> {
     /// The transition store.
     transitions: TransitionStore<N, B::TransitionStorage>,
     /// The validators.
-    // TODO (howardwu): Update this to retrieve from a validators store.
     validators: IndexMap<Address<N>, ()>,
     /// The memory pool of unconfirmed transactions.
     memory_pool: IndexMap<N::TransactionID, Transaction<N>>,
+    /// The cached genesis block.
+    genesis: OnceCell<Block<N>>,
+    /// The expected timestamp of the genesis block.
+    genesis_timestamp: i64,
+    /// The times at which the most recent blocks were added.
+    block_times: VecDeque<Instant>,
+    /// The cache of transaction verification results.
+    verification_cache: VerificationCache<N>,
+    /// The filter of commitments in the ledger.
+    commitment_filter: CommitmentFilter<N>,
     /// The VM state.
     vm: VM<N, P>,
     // /// The mapping of program IDs to their global state.
@@ -120,6 +239,17 @@ impl<N: Network> Ledger<N, BlockMemory<N>, ProgramMemory<N>> {
 impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
     /// Initializes a new instance of `Ledger` with the given genesis block.
     pub fn new_with_genesis(genesis: &Block<N>, address: Address<N>, dev: Option<u16>) -> Result<Self> {
+        Self::new_with_genesis_and_timestamp(genesis, address, N::GENESIS_TIMESTAMP, dev)
+    }
+
+    /// Initializes a new instance of `Ledger` with the given genesis block,
+    /// which must have the given genesis timestamp instead of `N::GENESIS_TIMESTAMP`.
+    pub fn new_with_genesis_and_timestamp(
+        genesis: &Block<N>,
+        address: Address<N>,
+        genesis_timestamp: i64,
+        dev: Option<u16>,
+    ) -> Result<Self> {
         // Initialize the block store.
         let blocks = BlockStore::<N, B>::open(dev)?;
         // Initialize the program store.
@@ -138,15 +268,23 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
             current_height: 0,
             current_round: 0,
             block_tree: N::merkle_tree_bhp(&[])?,
+            state_roots: Default::default(),
             transactions: blocks.transaction_store().clone(),
             transitions: blocks.transition_store().clone(),
             blocks,
-            // TODO (howardwu): Update this to retrieve from a validators store.
-            validators: [(address, ())].into_iter().collect(),
+            validators: Default::default(),
             vm,
             memory_pool: Default::default(),
+            genesis: Default::default(),
+            genesis_timestamp,
+            block_times: Default::default(),
+            verification_cache: Default::default(),
+            commitment_filter: Default::default(),
         };
 
+        // Add the initial validator.
+        ledger.add_validator(address)?;
+
         // Add the genesis block.
         ledger.add_next_block(genesis)?;
 
@@ -175,13 +313,18 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
             current_height: 0,
             current_round: 0,
             block_tree: N::merkle_tree_bhp(&[])?,
+            state_roots: Default::default(),
             transactions: blocks.transaction_store().clone(),
             transitions: blocks.transition_store().clone(),
+            validators: blocks.validator_store().validators().map(|address| (*address, ())).collect(),
             blocks,
-            // TODO (howardwu): Update this to retrieve from a validators store.
-            validators: Default::default(),
             vm,
             memory_pool: Default::default(),
+            genesis: Default::default(),
+            genesis_timestamp: N::GENESIS_TIMESTAMP,
+            block_times: Default::default(),
+            verification_cache: Default::default(),
+            commitment_filter: Default::default(),
         };
 
         // Fetch the latest height.
@@ -198,9 +341,14 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
             }
         };
 
-        // Add the initial validator.
-        let genesis_block = ledger.get_block(0)?;
-        ledger.add_validator(genesis_block.signature().to_address())?;
+        // If the validator set is empty, add the initial validator.
+        if ledger.validators.is_empty() {
+            let genesis_block = ledger.genesis_block()?;
+            ledger.add_validator(genesis_block.signature().to_address())?;
+        }
+
+        // Set the genesis timestamp to the timestamp of the stored genesis block.
+        ledger.genesis_timestamp = ledger.get_header(0)?.timestamp();
 
         // Fetch the latest block.
         let block = ledger.get_block(latest_height)?;
@@ -210,11 +358,18 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
         ledger.current_height = block.height();
         ledger.current_round = block.round();
 
-        // TODO (howardwu): Improve the performance here by using iterators.
         // Generate the block tree.
-        let hashes: Vec<_> =
-            (0..=latest_height).map(|height| ledger.get_hash(height).map(|hash| hash.to_bits_le())).try_collect()?;
-        ledger.block_tree.append(&hashes)?;
+        ledger.rebuild_block_tree()?;
+
+        // Index the historical state roots, as each block header contains the state root of the previous block.
+        for height in 1..=latest_height {
+            let previous_state_root = *ledger.get_header(height)?.previous_state_root();
+            ledger.state_roots.insert(previous_state_root.into(), height - 1);
+        }
+        ledger.state_roots.insert((*ledger.block_tree.root()).into(), latest_height);
+
+        // Populate the commitment filter, sized for the number of commitments.
+        ledger.rebuild_commitment_filter();
 
         // Safety check the existence of every block.
         #[cfg(feature = "parallel")]
@@ -241,42 +396,164 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
             bail!("Transaction '{}' already exists in the memory pool.", transaction.id());
         }
 
-        // Check that the transaction is well formed and unique.
-        self.check_transaction(&transaction)?;
+        // Check that the transaction is well formed and unique, allowing it to spend records created in the memory pool.
+        self.check_transaction_with_pending(&transaction, &self.memory_pool_commitments())?;
 
         // Insert the transaction to the memory pool.
         self.memory_pool.insert(transaction.id(), transaction);
         Ok(())
     }
 
+    /// Re-verifies every transaction in the memory pool against the current ledger state,
+    /// removes the transactions that are no longer valid, and returns their IDs.
+    pub fn reverify_memory_pool(&mut self) -> Vec<N::TransactionID> {
+        // Find the transactions that are no longer valid.
+        let pending_commitments = self.memory_pool_commitments();
+        let invalid_ids = self
+            .memory_pool
+            .iter()
+            .filter(|(_, transaction)| self.check_transaction_with_pending(transaction, &pending_commitments).is_err())
+            .map(|(transaction_id, _)| *transaction_id)
+            .collect::<Vec<_>>();
+        // Remove the invalid transactions from the memory pool.
+        for transaction_id in &invalid_ids {
+            self.memory_pool.remove(transaction_id);
+        }
+        invalid_ids
+    }
+
+    /// Returns the transactions in the memory pool, in the order in which they were added,
+    /// such that they can be persisted and restored with `import_memory_pool`.
+    pub fn export_memory_pool(&self) -> Vec<Transaction<N>> {
+        self.memory_pool.values().cloned().collect()
+    }
+
+    /// Adds the given transactions to the memory pool, in order, re-validating each against the current ledger state.
+    /// Returns the result of adding each transaction, where the transactions that are no longer valid are dropped.
+    pub fn import_memory_pool(&mut self, transactions: Vec<Transaction<N>>) -> Vec<(N::TransactionID, Result<()>)> {
+        transactions.into_iter().map(|transaction| (transaction.id(), self.add_to_memory_pool(transaction))).collect()
+    }
+
     /// Returns a candidate for the next block in the ledger.
     pub fn propose_next_block<R: Rng + CryptoRng>(&self, private_key: &PrivateKey<N>, rng: &mut R) -> Result<Block<N>> {
+        // Gather the transactions from the memory pool, in order of descending fee.
+        let transactions = self.memory_pool_sorted().into_iter().cloned().collect();
+        // Construct the block.
+        self.propose_next_block_with(private_key, transactions, rng)
+    }
+
+    /// Returns a candidate for the next block in the ledger, from the given transactions (instead of the memory pool).
+    /// Transactions with input IDs, commitments, or nonces that collide with an earlier transaction are excluded.
+    /// Transactions that spend a record created by another given transaction are included after that transaction,
+    /// and are excluded if that transaction is not included.
+    /// Transactions that would increase the block size beyond `N::MAX_BLOCK_SIZE` are excluded.
+    /// Transactions with a transition fee below `N::MINIMUM_FEE` are excluded.
+    pub fn propose_next_block_with<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        transactions: Vec<Transaction<N>>,
+        rng: &mut R,
+    ) -> Result<Block<N>> {
+        self.propose_next_block_with_limits(private_key, transactions, N::MAX_BLOCK_SIZE, N::MINIMUM_FEE, rng)
+    }
+
+    /// Returns a candidate for the next block in the ledger, from the given transactions,
+    /// excluding the transactions that would increase the block size beyond the given maximum block size,
+    /// and the transactions with a transition fee below the given minimum fee.
+    fn propose_next_block_with_limits<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        transactions: Vec<Transaction<N>>,
+        max_block_size: usize,
+        minimum_fee: i64,
+        rng: &mut R,
+    ) -> Result<Block<N>> {
+        // Fetch the latest block and state root.
+        let block = self.latest_block()?;
+        let state_root = self.latest_state_root();
+
         // Construct the transactions for the block.
         let transactions = {
             // TODO (raychu86): Add more sophisticated logic for transaction selection.
 
-            // Add the transactions from the memory pool that do not have input collisions.
+            // Add the given transactions that do not have input or output collisions.
             let mut transcations = Vec::new();
             let mut input_ids = Vec::new();
+            let mut commitments = Vec::new();
+            let mut nonces = Vec::new();
 
-            'outer: for transaction in self.memory_pool.values() {
-                for input_id in transaction.input_ids() {
-                    if input_ids.contains(&input_id) {
-                        continue 'outer;
+            // The size of a block without its transactions is fixed, so it is measured from the latest block.
+            let mut block_size = block.size_in_bytes()?;
+            for transaction in block.transactions().values() {
+                block_size -= transaction.to_bytes_le()?.len();
+            }
+
+            // Add the transactions in rounds, deferring the transactions that spend a record
+            // created by a transaction that is not yet added, until no more transactions can be added.
+            let mut pending = transactions;
+            loop {
+                let num_transactions = transcations.len();
+                let mut deferred = Vec::new();
+
+                'outer: for transaction in pending {
+                    // Skip the transaction if a transition fee is below the minimum fee.
+                    if transaction.transitions().any(|transition| *transition.fee() < minimum_fee) {
+                        continue;
+                    }
+                    for input_id in transaction.input_ids() {
+                        if input_ids.contains(input_id) {
+                            continue 'outer;
+                        }
+                    }
+                    for commitment in transaction.commitments() {
+                        if commitments.contains(commitment) {
+                            continue 'outer;
+                        }
+                    }
+                    for nonce in transaction.nonces() {
+                        if nonces.contains(nonce) {
+                            continue 'outer;
+                        }
+                    }
+
+                    // Defer the transaction if it spends a record created by a transaction that is not yet added.
+                    let mut is_deferred = false;
+                    for origin in transaction.origins() {
+                        if let Origin::Commitment(commitment) = origin {
+                            if !commitments.contains(commitment) && !self.contains_commitment(commitment)? {
+                                is_deferred = true;
+                                break;
+                            }
+                        }
                     }
+                    if is_deferred {
+                        deferred.push(transaction);
+                        continue;
+                    }
+
+                    // Skip the transaction if it would increase the block size beyond the maximum block size.
+                    let transaction_size = transaction.to_bytes_le()?.len();
+                    if block_size + transaction_size > max_block_size {
+                        continue;
+                    }
+                    block_size += transaction_size;
+
+                    input_ids.extend(transaction.input_ids().copied());
+                    commitments.extend(transaction.commitments().copied());
+                    nonces.extend(transaction.nonces().copied());
+                    transcations.push(transaction);
                 }
 
-                transcations.push(transaction);
-                input_ids.extend(transaction.input_ids());
+                // Stop if there are no deferred transactions, or if no transactions were added in this round.
+                if deferred.is_empty() || transcations.len() == num_transactions {
+                    break;
+                }
+                pending = deferred;
             }
 
             transcations.into_iter().collect::<Transactions<N>>()
         };
 
-        // Fetch the latest block and state root.
-        let block = self.latest_block()?;
-        let state_root = self.latest_state_root();
-
         // TODO (raychu86): Establish the correct round, coinbase target, and proof target.
         let round = block.round() + 1;
         let coinbase_target = u64::MAX;
@@ -327,10 +604,16 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
             bail!("The given block has an incorrect round number")
         }
 
-        // TODO (raychu86): Ensure the next block timestamp is the median of proposed blocks.
-        // Ensure the next block timestamp is after the current block timestamp.
-        if block.height() > 0 && block.header().timestamp() <= self.latest_block()?.header().timestamp() {
-            bail!("The given block timestamp is before the current timestamp")
+        // Ensure the next block timestamp is after the median timestamp of the recent blocks.
+        // Note: The timestamp may precede the current block timestamp, as long as it is after the median.
+        if block.height() > 0 && block.timestamp() <= self.latest_median_timestamp()? {
+            bail!("The given block timestamp is not after the median timestamp of the recent blocks")
+        }
+
+        // Ensure the next block timestamp is not too far in the future.
+        let max_drift = i64::from(N::ANCHOR_TIME) * i64::from(N::MAX_FUTURE_TIMESTAMP_ANCHORS);
+        if block.timestamp() > OffsetDateTime::now_utc().unix_timestamp().saturating_add(max_drift) {
+            bail!("The given block timestamp is too far in the future")
         }
 
         // TODO (raychu86): Add proof and coinbase target verification.
@@ -342,14 +625,50 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
             }
         }
 
+        /* Block Uniqueness */
+
+        // Ensure there are no duplicate serial numbers in the block.
+        if has_duplicates(block.serial_numbers()) {
+            bail!("Found duplicate serial numbers in block {}", block.height())
+        }
+
+        // Ensure there are no duplicate commitments in the block.
+        if has_duplicates(block.commitments()) {
+            bail!("Found duplicate commitments in block {}", block.height())
+        }
+
+        // Ensure there are no duplicate nonces in the block.
+        if has_duplicates(block.nonces()) {
+            bail!("Found duplicate nonces in block {}", block.height())
+        }
+
+        // Ensure there are no duplicate transition public keys in the block.
+        if has_duplicates(block.transition_public_keys()) {
+            bail!("Found duplicate transition public keys in block {}", block.height())
+        }
+
+        // Ensure there are no duplicate transition commitments in the block.
+        if has_duplicates(block.transitions().map(Transition::tcm)) {
+            bail!("Found duplicate transition commitments in block {}", block.height())
+        }
+
         /* Input */
 
+        // Collect the commitments in the block, along with the number of commitments created before each transaction,
+        // as a transaction may spend a record created by an earlier transaction in the block.
+        let mut block_commitments = Vec::new();
+        let mut num_earlier_commitments = Vec::with_capacity(block.transactions().len());
+        for transaction in block.transactions().values() {
+            num_earlier_commitments.push(block_commitments.len());
+            block_commitments.extend(transaction.commitments().copied());
+        }
+
         // Ensure that the origin are valid.
         for origin in block.origins() {
             match origin {
-                // Check that the commitment exists in the ledger.
+                // Check that the commitment exists in the ledger or in the block.
                 Origin::Commitment(commitment) => {
-                    if !self.contains_commitment(commitment)? {
+                    if !block_commitments.contains(commitment) && !self.contains_commitment(commitment)? {
                         bail!("The given transaction references a non-existent commitment {}", &commitment)
                     }
                 }
@@ -396,12 +715,45 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
         /* Block Header */
 
         // If the block is the genesis block, check that it is valid.
-        if block.height() == 0 && !block.is_genesis() {
+        if block.height() == 0 && !block.is_genesis_with_timestamp(self.genesis_timestamp) {
             bail!("Invalid genesis block");
         }
 
-        // Ensure the block header is valid.
-        if !block.header().is_valid() {
+        // Ensure the previous state root matches the latest state root.
+        if block.height() > 0 && block.previous_state_root() != self.latest_state_root() {
+            bail!("Block {} ({}) has an incorrect previous state root", block.height(), block.hash());
+        }
+
+        // Ensure the block is well-formed.
+        self.check_block_integrity(block)?;
+
+        /* Transactions */
+
+        // Ensure each transaction is well-formed and unique, allowing it to spend records created by earlier transactions.
+        let transactions = block.transactions().values().zip_eq(num_earlier_commitments).collect::<Vec<_>>();
+        #[cfg(feature = "parallel")]
+        let transactions_iter = transactions.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let mut transactions_iter = transactions.iter();
+        transactions_iter.try_for_each(|(transaction, num_earlier_commitments)| {
+            self.check_transaction_with_pending(transaction, &block_commitments[..*num_earlier_commitments])
+                .map_err(|e| anyhow!("Invalid transaction '{}' found in the transactions list: {e}", transaction.id()))
+        })?;
+
+        /* Fees */
+
+        // Ensure the fee is correct for each transition.
+        Self::check_transition_fees(block, N::MINIMUM_FEE)
+    }
+
+    /// Checks the given block is well-formed, independent of the ledger state.
+    fn check_block_integrity(&self, block: &Block<N>) -> Result<()> {
+        // Ensure the block header is valid. A genesis block header is checked against the configured genesis timestamp.
+        let is_valid_header = match block.height() == 0 {
+            true => block.header().is_genesis_with_timestamp(self.genesis_timestamp),
+            false => block.header().is_valid(),
+        };
+        if !is_valid_header {
             bail!("Invalid block header: {:?}", block.header());
         }
 
@@ -469,18 +821,26 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
             bail!("Cannot validate a block with more than {} transactions", Transactions::<N>::MAX_TRANSACTIONS);
         }
 
-        // Ensure each transaction is well-formed and unique.
-        #[cfg(feature = "parallel")]
-        let transactions_iter = block.transactions().par_iter();
-        #[cfg(not(feature = "parallel"))]
-        let mut transactions_iter = block.transactions().iter();
-        transactions_iter.try_for_each(|(_, transaction)| {
-            self.check_transaction(transaction)
-                .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))
-        })?;
+        // Ensure the block size is within the allowed range.
+        Self::check_block_size(block, N::MAX_BLOCK_SIZE)
+    }
 
-        /* Fees */
+    /// Checks the size of the given block does not exceed the given maximum block size.
+    fn check_block_size(block: &Block<N>, max_block_size: usize) -> Result<()> {
+        let block_size = block.size_in_bytes()?;
+        if block_size > max_block_size {
+            bail!(
+                "Block {} ({}) is {block_size} bytes, which exceeds {max_block_size} bytes",
+                block.height(),
+                block.hash()
+            )
+        }
+        Ok(())
+    }
 
+    /// Checks the fee of each transition in the given block is at least the given minimum fee,
+    /// and that the genesis function is not called, unless the block is the genesis block.
+    fn check_transition_fees(block: &Block<N>, minimum_fee: i64) -> Result<()> {
         // Prepare the block height, credits program ID, and genesis function name.
         let height = block.height();
         let credits_program_id = ProgramID::from_str("credits.aleo")?;
@@ -497,12 +857,62 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
                 if transition.fee().is_negative() {
                     bail!("The transition fee cannot be negative.");
                 }
+                // Ensure the transition fee is at least the minimum fee.
+                if *transition.fee() < minimum_fee {
+                    bail!(
+                        "The fee of transition '{}' is {}, which is below the minimum fee of {minimum_fee}",
+                        transition.id(),
+                        transition.fee()
+                    );
+                }
             }
         }
 
         Ok(())
     }
 
+    /// Checks the given batch of blocks is a valid continuation of the chain, before any of the blocks are added.
+    /// This checks the blocks are chained to the latest block and to each other, and checks the contents of each
+    /// block that do not depend on the ledger state, such as the block hash, signature, and transaction proofs.
+    pub fn verify_block_batch(&self, blocks: &[Block<N>]) -> Result<()> {
+        // Ensure the blocks are chained to the latest block, and to each other.
+        let (mut previous_hash, mut previous_height) = (self.current_hash, self.current_height);
+        for block in blocks {
+            // Ensure the previous block hash is correct.
+            if block.previous_hash() != previous_hash {
+                bail!("Block {} ({}) has an incorrect previous block hash", block.height(), block.hash())
+            }
+            // Ensure the block height is correct.
+            if block.height() != previous_height + 1 {
+                bail!("Block {} ({}) has an incorrect block height", block.height(), block.hash())
+            }
+            previous_hash = block.hash();
+            previous_height = block.height();
+        }
+
+        // Ensure each block is well-formed, and its transactions are valid.
+        #[cfg(feature = "parallel")]
+        let blocks_iter = blocks.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let mut blocks_iter = blocks.iter();
+        blocks_iter.try_for_each(|block| {
+            self.check_block_integrity(block)?;
+            for (transaction_id, transaction) in block.transactions().iter() {
+                if !self
+                    .verification_cache
+                    .verify_transaction(self.current_hash, transaction, || self.vm.verify(transaction))
+                {
+                    bail!(
+                        "Block {} ({}) contains an invalid transaction '{transaction_id}'",
+                        block.height(),
+                        block.hash()
+                    )
+                }
+            }
+            Ok(())
+        })
+    }
+
     /// Adds the given block as the next block in the chain.
     pub fn add_next_block(&mut self, block: &Block<N>) -> Result<()> {
         // Ensure the given block is a valid next block.
@@ -519,11 +929,35 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
             ledger.current_height = block.height();
             ledger.current_round = block.round();
             ledger.block_tree.append(&[block.hash().to_bits_le()])?;
-            ledger.blocks.insert(block)?;
+            ledger.state_roots.insert((*ledger.block_tree.root()).into(), block.height());
+
+            // Stage the block in the block store, and update the VM. The block is only
+            // committed to the block store if every transaction is finalized successfully,
+            // and otherwise the VM is restored to its state before the block.
+            ledger.blocks.start_atomic();
+            let result = || -> Result<()> {
+                ledger.blocks.insert(block)?;
+                ledger.vm.finalize_all(block.transactions().values())
+            }();
+            if let Err(error) = result {
+                ledger.blocks.abort_atomic();
+                return Err(error);
+            }
+            ledger.blocks.finish_atomic()?;
 
-            // Update the VM.
-            for transaction in block.transactions().values() {
-                ledger.vm.finalize(transaction)?;
+            // Add the commitments to the commitment filter.
+            for commitment in block.transactions().commitments() {
+                ledger.commitment_filter.insert(commitment);
+            }
+            // Rebuild the commitment filter with a larger size, if it holds too many commitments.
+            if ledger.commitment_filter.is_overloaded() {
+                ledger.rebuild_commitment_filter();
+            }
+
+            // Record the time at which the block was added.
+            ledger.block_times.push_back(Instant::now());
+            if ledger.block_times.len() > NUM_RECENT_BLOCK_TIMES {
+                ledger.block_times.pop_front();
             }
 
             // Clear the memory pool of these transactions.
@@ -532,41 +966,148 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
             }
 
             // Clear the memory pool of the transactions that are now invalid.
-            ledger.memory_pool.retain(|_, transaction| self.check_transaction(transaction).is_ok());
+            // The transactions are checked against the updated ledger, which includes the new block.
+            let pending_commitments = ledger.memory_pool_commitments();
+            let mut memory_pool = std::mem::take(&mut ledger.memory_pool);
+            memory_pool.retain(|transaction_id, transaction| {
+                match ledger.transaction_rejection_reason_with_pending(transaction, &pending_commitments) {
+                    Some(reason) => {
+                        warn!("Removed transaction '{transaction_id}' from the memory pool: {reason}");
+                        false
+                    }
+                    None => true,
+                }
+            });
+            ledger.memory_pool = memory_pool;
 
             *self = Self {
                 current_hash: ledger.current_hash,
                 current_height: ledger.current_height,
                 current_round: ledger.current_round,
                 block_tree: ledger.block_tree,
+                state_roots: ledger.state_roots,
                 blocks: ledger.blocks,
                 transactions: ledger.transactions,
                 transitions: ledger.transitions,
                 validators: ledger.validators,
                 vm: ledger.vm,
                 memory_pool: ledger.memory_pool,
+                genesis: ledger.genesis,
+                genesis_timestamp: ledger.genesis_timestamp,
+                block_times: ledger.block_times,
+                verification_cache: ledger.verification_cache,
+                commitment_filter: ledger.commitment_filter,
             };
         }
 
         Ok(())
     }
 
+    /// Returns the block for the given block height, serialized in its versioned byte format.
+    pub fn export_block(&self, height: u32) -> Result<Vec<u8>> {
+        self.get_block(height)?.to_bytes_le()
+    }
+
+    /// Deserializes the given versioned block bytes, and adds the block as the next block in the chain.
+    pub fn import_block(&mut self, bytes: &[u8]) -> Result<()> {
+        // Deserialize the block.
+        let block = Block::from_bytes_le(bytes)?;
+        // Add the block to the ledger.
+        self.add_next_block(&block)
+    }
+
+    /// Writes every block from genesis to the latest block into the given writer, as a block archive.
+    /// The archive is the number of blocks, followed by each length-prefixed block.
+    pub fn export_blocks<W: Write>(&self, mut writer: W) -> Result<()> {
+        // Write the number of blocks.
+        let num_blocks = self.current_height.checked_add(1).ok_or_else(|| anyhow!("Block archive is too large"))?;
+        num_blocks.write_le(&mut writer)?;
+        // Write each block, prefixed by its length in bytes.
+        for height in 0..num_blocks {
+            let bytes = self.export_block(height)?;
+            u32::try_from(bytes.len())?.write_le(&mut writer)?;
+            writer.write_all(&bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Reads a block archive from the given reader, and adds each new block to the ledger.
+    /// Blocks at heights already in the ledger (including the genesis block) must match the existing blocks.
+    /// Returns the number of blocks imported.
+    pub fn import_blocks<R: Read>(&mut self, mut reader: R) -> Result<u32> {
+        // Read the number of blocks.
+        let num_blocks = u32::read_le(&mut reader)?;
+
+        let mut num_imported = 0;
+        for height in 0..num_blocks {
+            // Read the block.
+            let num_bytes = u32::read_le(&mut reader)? as usize;
+            // Ensure the block size is within bounds, before allocating the buffer.
+            if num_bytes > N::MAX_BLOCK_SIZE {
+                bail!("Block {height} in the archive exceeds the maximum block size ({num_bytes} bytes)")
+            }
+            let mut bytes = vec![0u8; num_bytes];
+            reader.read_exact(&mut bytes)?;
+            let block = Block::<N>::from_bytes_le(&bytes)?;
+
+            // Ensure the block is at the expected height.
+            if block.height() != height {
+                bail!("Block archive is out of order: expected block {height}, found block {}", block.height())
+            }
+
+            // Reconcile the blocks that already exist in the ledger.
+            if height <= self.current_height {
+                if block.hash() != self.get_hash(height)? {
+                    bail!("Block {height} in the archive does not match the existing block {height}")
+                }
+                continue;
+            }
+
+            // Add the block to the ledger.
+            self.add_next_block(&block)?;
+            num_imported += 1;
+        }
+        Ok(num_imported)
+    }
+
+    /// Reconstructs the block tree from the stored block hashes, and returns the recomputed state root.
+    pub fn rebuild_block_tree(&mut self) -> Result<N::StateRoot> {
+        // TODO (howardwu): Improve the performance here by using iterators.
+        // Retrieve the block hashes, up to the current height.
+        let hashes: Vec<_> = (0..=self.current_height)
+            .map(|height| self.get_hash(height).map(|hash| hash.to_bits_le()))
+            .try_collect()?;
+        // Replace the block tree.
+        self.block_tree = N::merkle_tree_bhp(&hashes)?;
+        Ok((*self.block_tree.root()).into())
+    }
+
+    /// Rebuilds the commitment filter from the commitments in the ledger, sized for their number.
+    fn rebuild_commitment_filter(&self) {
+        let num_commitments = self.commitments().count();
+        self.commitment_filter.rebuild(num_commitments, self.commitments());
+    }
+
     /// Adds a given address to the validator set.
     pub fn add_validator(&mut self, address: Address<N>) -> Result<()> {
-        if self.validators.insert(address, ()).is_some() {
+        if self.validators.contains_key(&address) {
             bail!("'{address}' is already in the validator set.")
-        } else {
-            Ok(())
         }
+        // Persist the validator to storage.
+        self.blocks.validator_store().insert(address)?;
+        self.validators.insert(address, ());
+        Ok(())
     }
 
     /// Removes a given address from the validator set.
     pub fn remove_validator(&mut self, address: Address<N>) -> Result<()> {
-        if self.validators.remove(&address).is_none() {
+        if !self.validators.contains_key(&address) {
             bail!("'{address}' is not in the validator set.")
-        } else {
-            Ok(())
         }
+        // Remove the validator from storage.
+        self.blocks.validator_store().remove(&address)?;
+        self.validators.remove(&address);
+        Ok(())
     }
 
     /// Returns the block tree.
@@ -584,8 +1125,113 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
         &self.memory_pool
     }
 
+    /// Clears the cache of transaction verification results.
+    pub fn clear_verification_cache(&self) {
+        self.verification_cache.clear();
+    }
+
+    /// Returns the transactions in the memory pool, sorted by descending total fee,
+    /// with ties broken by ascending transaction ID. This is the order used by the proposer.
+    pub fn memory_pool_sorted(&self) -> Vec<&Transaction<N>> {
+        let mut transactions = self.memory_pool.values().collect::<Vec<_>>();
+        transactions.sort_by(|a, b| b.total_fee().cmp(&a.total_fee()).then_with(|| (*a.id()).cmp(&*b.id())));
+        transactions
+    }
+
+    /// Returns the commitments created by the transactions in the memory pool.
+    fn memory_pool_commitments(&self) -> Vec<Field<N>> {
+        self.memory_pool.values().flat_map(|transaction| transaction.commitments().copied()).collect()
+    }
+
+    /// Returns the number of blocks added per second, measured over the most recently-added blocks.
+    /// Returns `None` if fewer than two blocks have been added since the ledger was initialized.
+    pub fn recent_blocks_per_second(&self) -> Option<f64> {
+        let (first, last) = (self.block_times.front()?, self.block_times.back()?);
+        let elapsed = last.duration_since(*first).as_secs_f64();
+        match elapsed > 0.0 {
+            true => Some((self.block_times.len() - 1) as f64 / elapsed),
+            false => None,
+        }
+    }
+
+    /// Returns the estimated time to sync to the given target height, at the given rate of blocks per second.
+    /// Returns `Duration::MAX` if the rate is not positive.
+    pub fn estimate_remaining_sync(&self, target_height: u32, recent_blocks_per_sec: f64) -> Duration {
+        // Compute the number of remaining blocks.
+        let num_remaining = target_height.saturating_sub(self.current_height);
+        if num_remaining == 0 {
+            return Duration::ZERO;
+        }
+        // Ensure the rate is positive.
+        if recent_blocks_per_sec.is_nan() || recent_blocks_per_sec <= 0.0 {
+            return Duration::MAX;
+        }
+        // Compute the remaining time.
+        Duration::try_from_secs_f64(f64::from(num_remaining) / recent_blocks_per_sec).unwrap_or(Duration::MAX)
+    }
+
     /// Returns a state path for the given commitment.
     pub fn to_state_path(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        // Find the transition, transaction, and block that contain the commitment.
+        let (transition_id, transaction_id, block_hash) = self.find_commitment_location(commitment)?;
+        // Retrieve the block.
+        let block = match self.blocks.get_block(&block_hash)? {
+            Some(block) => block,
+            None => bail!("The block '{block_hash}' for commitment '{commitment}' is not in the ledger"),
+        };
+        // Construct the block paths.
+        let block_paths = self.to_block_paths(&block)?;
+        // Construct the state path.
+        self.to_state_path_in_block(commitment, &transition_id, &transaction_id, &block, &block_paths)
+    }
+
+    /// Returns the state paths for the given commitments.
+    /// Each block is retrieved once, and its block header and block tree paths are shared by its commitments.
+    pub fn to_state_paths(&self, commitments: &[Field<N>]) -> Result<Vec<StatePath<N>>> {
+        // Find the transition, transaction, and block that contain each commitment.
+        let locations: Vec<_> =
+            commitments.iter().map(|commitment| self.find_commitment_location(commitment)).try_collect()?;
+
+        // Retrieve each block and construct its paths, once per block.
+        let mut blocks = IndexMap::new();
+        for (_, _, block_hash) in &locations {
+            if !blocks.contains_key(block_hash) {
+                let block = match self.blocks.get_block(block_hash)? {
+                    Some(block) => block,
+                    None => bail!("The block '{block_hash}' is not in the ledger"),
+                };
+                let block_paths = self.to_block_paths(&block)?;
+                blocks.insert(*block_hash, (block, block_paths));
+            }
+        }
+
+        // Construct the state paths.
+        commitments
+            .iter()
+            .zip_eq(&locations)
+            .map(|(commitment, (transition_id, transaction_id, block_hash))| {
+                let (block, block_paths) = &blocks[block_hash];
+                self.to_state_path_in_block(commitment, transition_id, transaction_id, block, block_paths)
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the given state path is for the given commitment,
+    /// and every Merkle path in the state path composes up to its state root.
+    pub fn verify_state_path(state_path: &StatePath<N>, commitment: &Field<N>) -> Result<bool> {
+        // Ensure the state path is for the given commitment.
+        if state_path.transition_leaf().id() != *commitment {
+            return Ok(false);
+        }
+        // Ensure the Merkle paths are valid.
+        Ok(state_path.verify().is_ok())
+    }
+
+    /// Returns the transition ID, transaction ID, and block hash that contain the given commitment.
+    fn find_commitment_location(
+        &self,
+        commitment: &Field<N>,
+    ) -> Result<(N::TransitionID, N::TransactionID, N::BlockHash)> {
         // Ensure the commitment exists.
         if !self.contains_commitment(commitment)? {
             bail!("Commitment '{commitment}' does not exist");
@@ -603,21 +1249,42 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
             Some(block_hash) => block_hash,
             None => bail!("The block hash for commitment '{commitment}' is not in the ledger"),
         };
+        Ok((transition_id, transaction_id, block_hash))
+    }
+
+    /// Returns the block header root, header leaf, header path, and block path for the given block.
+    fn to_block_paths(&self, block: &Block<N>) -> Result<BlockPaths<N>> {
+        // Construct the block header path.
+        let block_header = block.header();
+        let header_root = block_header.to_root()?;
+        let header_leaf = HeaderLeaf::<N>::new(1, *block_header.transactions_root());
+        let header_path = block_header.to_path(&header_leaf)?;
+
+        // Construct the block path.
+        let block_path = self.block_tree.prove(block.height() as usize, &block.hash().to_bits_le())?;
+
+        Ok((header_root, header_leaf, header_path, block_path))
+    }
 
+    /// Returns a state path for the given commitment, in the given block.
+    fn to_state_path_in_block(
+        &self,
+        commitment: &Field<N>,
+        transition_id: &N::TransitionID,
+        transaction_id: &N::TransactionID,
+        block: &Block<N>,
+        (header_root, header_leaf, header_path, block_path): &BlockPaths<N>,
+    ) -> Result<StatePath<N>> {
+        // Retrieve the transaction, and its index in the block.
+        let transactions = block.transactions();
+        let (transaction_index, _, transaction) = match transactions.get_full(transaction_id) {
+            Some(entry) => entry,
+            None => bail!("The transaction '{transaction_id}' for commitment '{commitment}' is not in the block"),
+        };
         // Retrieve the transition.
-        let transition = match self.transitions.get_transition(&transition_id)? {
+        let transition = match transaction.transitions().find(|transition| transition.id() == transition_id) {
             Some(transition) => transition,
-            None => bail!("The transition '{transition_id}' for commitment '{commitment}' is not in the ledger"),
-        };
-        // Retrieve the transaction.
-        let transaction = match self.transactions.get_transaction(&transaction_id)? {
-            Some(transaction) => transaction,
-            None => bail!("The transaction '{transaction_id}' for commitment '{commitment}' is not in the ledger"),
-        };
-        // Retrieve the block.
-        let block = match self.blocks.get_block(&block_hash)? {
-            Some(block) => block,
-            None => bail!("The block '{block_hash}' for commitment '{commitment}' is not in the ledger"),
+            None => bail!("The transition '{transition_id}' for commitment '{commitment}' is not in the transaction"),
         };
 
         // Construct the transition path and transaction leaf.
@@ -629,28 +1296,19 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
         let transaction_path = transaction.to_path(&transaction_leaf)?;
 
         // Construct the transactions path.
-        let transactions = block.transactions();
-        let transaction_index = transactions.iter().position(|(id, _)| id == &transaction.id()).unwrap();
         let transactions_path = transactions.to_path(transaction_index, *transaction.id())?;
 
-        // Construct the block header path.
-        let block_header = block.header();
-        let header_root = block_header.to_root()?;
-        let header_leaf = HeaderLeaf::<N>::new(1, *block_header.transactions_root());
-        let header_path = block_header.to_path(&header_leaf)?;
-
-        // Construct the state root and block path.
+        // Retrieve the state root.
         let state_root = *self.block_tree.root();
-        let block_path = self.block_tree.prove(block.height() as usize, &block.hash().to_bits_le())?;
 
         StatePath::new(
             state_root.into(),
-            block_path,
+            block_path.clone(),
             block.hash(),
             block.previous_hash(),
-            header_root,
-            header_path,
-            header_leaf,
+            *header_root,
+            header_path.clone(),
+            header_leaf.clone(),
             transactions_path,
             transaction.id(),
             transaction_path,
@@ -672,55 +1330,103 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
 
     /// Checks the given transaction is well formed and unique.
     pub fn check_transaction(&self, transaction: &Transaction<N>) -> Result<()> {
-        let transaction_id = transaction.id();
+        self.check_transaction_with_pending(transaction, &[])
+    }
+
+    /// Checks the given transaction finalizes successfully against the current state, without modifying the ledger.
+    /// Unlike `check_transaction`, this runs the finalize logic of the transaction, but does not check it is unique.
+    pub fn simulate_transaction(&self, transaction: &Transaction<N>) -> Result<()> {
+        self.vm.simulate_finalize(transaction)
+    }
 
-        // Ensure the transaction is valid.
-        if !self.vm.verify(transaction) {
-            bail!("Transaction '{transaction_id}' is invalid")
+    /// Checks that the given transaction is well formed and unique, where the transaction
+    /// may also spend records with the given pending commitments, which are not yet in the ledger.
+    fn check_transaction_with_pending(
+        &self,
+        transaction: &Transaction<N>,
+        pending_commitments: &[Field<N>],
+    ) -> Result<()> {
+        match self.transaction_rejection_reason_with_pending(transaction, pending_commitments) {
+            Some(error) => Err(error.into()),
+            None => Ok(()),
         }
+    }
+
+    /// Returns the first reason for which the given transaction is not well formed or unique, if any.
+    pub fn transaction_rejection_reason(&self, transaction: &Transaction<N>) -> Option<TransactionError<N>> {
+        self.transaction_rejection_reason_with_pending(transaction, &[])
+    }
+
+    /// Returns the first reason for which the given transaction is not well formed or unique, if any, where the
+    /// transaction may also spend records with the given pending commitments, which are not yet in the ledger.
+    fn transaction_rejection_reason_with_pending(
+        &self,
+        transaction: &Transaction<N>,
+        pending_commitments: &[Field<N>],
+    ) -> Option<TransactionError<N>> {
+        self.validate_transaction_with_pending(transaction, pending_commitments).into_iter().next()
+    }
+
+    /// Returns every reason for which the given transaction is not well formed or unique.
+    /// If the returned list is empty, the transaction is valid.
+    pub fn validate_transaction(&self, transaction: &Transaction<N>) -> Vec<TransactionError<N>> {
+        self.validate_transaction_with_pending(transaction, &[])
+    }
+
+    /// Returns every reason for which the given transaction is not well formed or unique, where the
+    /// transaction may also spend records with the given pending commitments, which are not yet in the ledger.
+    fn validate_transaction_with_pending(
+        &self,
+        transaction: &Transaction<N>,
+        pending_commitments: &[Field<N>],
+    ) -> Vec<TransactionError<N>> {
+        let transaction_id = transaction.id();
+
+        let mut errors = Vec::new();
+        // Records the given error if the given check is true, or if the check itself failed.
+        let mut check = |is_error: Result<bool>, error: TransactionError<N>| match is_error {
+            Ok(true) => errors.push(error),
+            Ok(false) => (),
+            Err(storage_error) => errors.push(TransactionError::Storage(storage_error.to_string())),
+        };
+
+        // Ensure the transaction is valid (using the cached result, if available for the latest block).
+        let is_valid =
+            self.verification_cache.verify_transaction(self.current_hash, transaction, || self.vm.verify(transaction));
+        check(Ok(!is_valid), TransactionError::Invalid(transaction_id));
 
         // Ensure the ledger does not already contain the given transaction ID.
-        if self.contains_transaction_id(&transaction_id)? {
-            bail!("Transaction '{transaction_id}' already exists in the ledger")
-        }
+        check(self.contains_transaction_id(&transaction_id), TransactionError::DuplicateTransactionID(transaction_id));
 
         /* Input */
 
         // Ensure the ledger does not already contain the given input ID.
         for input_id in transaction.input_ids() {
-            if self.contains_input_id(input_id)? {
-                bail!("Input ID '{input_id}' already exists in the ledger")
-            }
+            check(self.contains_input_id(input_id), TransactionError::DuplicateInputID(*input_id));
         }
 
         // Ensure the ledger does not already contain a given serial numbers.
         for serial_number in transaction.serial_numbers() {
-            if self.contains_serial_number(serial_number)? {
-                bail!("Serial number '{serial_number}' already exists in the ledger")
-            }
+            check(self.contains_serial_number(serial_number), TransactionError::DuplicateSerialNumber(*serial_number));
         }
 
         // Ensure the ledger does not already contain a given tag.
         for tag in transaction.tags() {
-            if self.contains_tag(tag)? {
-                bail!("Tag '{tag}' already exists in the ledger")
-            }
+            check(self.contains_tag(tag), TransactionError::DuplicateTag(*tag));
         }
 
         // Ensure that the origin are valid.
         for origin in transaction.origins() {
             match origin {
-                // Check that the commitment exists in the ledger.
-                Origin::Commitment(commitment) => {
-                    if !self.contains_commitment(commitment)? {
-                        bail!("The given transaction references a non-existent commitment {}", &commitment)
-                    }
-                }
+                // Check that the commitment exists in the ledger or is pending.
+                Origin::Commitment(commitment) => check(
+                    self.contains_commitment(commitment)
+                        .map(|exists| !exists && !pending_commitments.contains(commitment)),
+                    TransactionError::UnknownCommitment(*commitment),
+                ),
                 // TODO (raychu86): Ensure that the state root exists in the ledger.
                 // Check that the state root is an existing state root.
-                Origin::StateRoot(_state_root) => {
-                    bail!("State roots are currently not supported (yet)")
-                }
+                Origin::StateRoot(_state_root) => check(Ok(true), TransactionError::UnsupportedStateRoot),
             }
         }
 
@@ -728,23 +1434,17 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
 
         // Ensure the ledger does not already contain the given output ID.
         for output_id in transaction.output_ids() {
-            if self.contains_output_id(output_id)? {
-                bail!("Output ID '{output_id}' already exists in the ledger")
-            }
+            check(self.contains_output_id(output_id), TransactionError::DuplicateOutputID(*output_id));
         }
 
         // Ensure the ledger does not already contain a given commitments.
         for commitment in transaction.commitments() {
-            if self.contains_commitment(commitment)? {
-                bail!("Commitment '{commitment}' already exists in the ledger")
-            }
+            check(self.contains_commitment(commitment), TransactionError::DuplicateCommitment(*commitment));
         }
 
         // Ensure the ledger does not already contain a given nonces.
         for nonce in transaction.nonces() {
-            if self.contains_nonce(nonce)? {
-                bail!("Nonce '{nonce}' already exists in the ledger")
-            }
+            check(self.contains_nonce(nonce), TransactionError::DuplicateNonce(*nonce));
         }
 
         /* Program */
@@ -752,33 +1452,47 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
         // Ensure that the ledger does not already contain the given program ID.
         if let Transaction::Deploy(_, deployment, _) = &transaction {
             let program_id = deployment.program_id();
-            if self.contains_program_id(program_id)? {
-                bail!("Program ID '{program_id}' already exists in the ledger")
-            }
+            check(self.contains_program_id(program_id), TransactionError::DuplicateProgramID(*program_id));
         }
 
         /* Metadata */
 
         // Ensure the ledger does not already contain a given transition public keys.
         for tpk in transaction.transition_public_keys() {
-            if self.contains_tpk(tpk)? {
-                bail!("Transition public key '{tpk}' already exists in the ledger")
-            }
+            check(self.contains_tpk(tpk), TransactionError::DuplicateTransitionPublicKey(*tpk));
         }
 
         // Ensure the ledger does not already contain a given transition commitment.
         for tcm in transaction.transition_commitments() {
-            if self.contains_tcm(tcm)? {
-                bail!("Transition commitment '{tcm}' already exists in the ledger")
-            }
+            check(self.contains_tcm(tcm), TransactionError::DuplicateTransitionCommitment(*tcm));
         }
 
-        Ok(())
+        errors
     }
 
-    // /// Adds the given transaction to the transaction store.
-    // pub fn insert(&mut self, transaction: Transaction<N>) -> Result<()> {
-    //     // Check that there are not collisions with existing transactions.
+    /// Checks the given transaction is well formed and unique, and does not have input collisions
+    /// with the given pending transactions.
+    pub fn check_transaction_against_pending(
+        &self,
+        transaction: &Transaction<N>,
+        pending: &[Transaction<N>],
+    ) -> Result<()> {
+        // Check that the transaction is well formed and unique.
+        self.check_transaction(transaction)?;
+
+        // Ensure the transaction does not share an input with the pending transactions.
+        for input_id in transaction.input_ids() {
+            if let Some(other) = pending.iter().find(|other| other.input_ids().any(|other_id| other_id == input_id)) {
+                bail!("Transaction '{}' shares input '{input_id}' with transaction '{}'", transaction.id(), other.id())
+            }
+        }
+
+        Ok(())
+    }
+
+    // /// Adds the given transaction to the transaction store.
+    // pub fn insert(&mut self, transaction: Transaction<N>) -> Result<()> {
+    //     // Check that there are not collisions with existing transactions.
     //     self.check_transaction(&transaction)?;
     //
     //     /* ATOMIC CODE SECTION */
@@ -917,10 +1631,14 @@ pub(crate) mod test_helpers {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ledger::test_helpers::CurrentLedger;
+    use crate::{ledger::test_helpers::CurrentLedger, Execution};
     use console::{network::Testnet3, program::Value};
     use snarkvm_utilities::TestRng;
 
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
     use tracing_test::traced_test;
 
     type CurrentNetwork = Testnet3;
@@ -1000,6 +1718,33 @@ mod tests {
         assert_eq!(ledger.latest_block().unwrap(), genesis);
     }
 
+    #[test]
+    fn test_validators_persist() {
+        let rng = &mut TestRng::default();
+
+        // Sample a new validator address.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Initialize the stores.
+        let blocks = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Initialize a ledger from the stores.
+        let mut ledger = CurrentLedger::from(blocks.clone(), store.clone()).unwrap();
+        assert!(!ledger.validators().contains_key(&address));
+
+        // Add the validator.
+        ledger.add_validator(address).unwrap();
+        assert!(ledger.validators().contains_key(&address));
+
+        // Reopen the ledger from the same stores.
+        let ledger = CurrentLedger::from(blocks, store).unwrap();
+        // Ensure the validator is still present.
+        assert!(ledger.validators().contains_key(&address));
+        assert_eq!(2, ledger.validators().len());
+    }
+
     #[test]
     fn test_state_path() {
         // Initialize the ledger with the genesis block.
@@ -1014,6 +1759,107 @@ mod tests {
         let _state_path = ledger.to_state_path(commitment).unwrap();
     }
 
+    #[test]
+    fn test_estimate_remaining_sync() {
+        // Initialize a new ledger.
+        let mut ledger = CurrentLedger::new(None).unwrap();
+        assert_eq!(ledger.latest_height(), 0);
+
+        // Ensure the estimate is computed from the gap and rate.
+        assert_eq!(ledger.estimate_remaining_sync(100, 4.0), Duration::from_secs(25));
+        assert_eq!(ledger.estimate_remaining_sync(1, 0.5), Duration::from_secs(2));
+        // Ensure the estimate is zero when there is no gap.
+        assert_eq!(ledger.estimate_remaining_sync(0, 4.0), Duration::ZERO);
+        // Ensure the estimate is unbounded when the rate is not positive.
+        assert_eq!(ledger.estimate_remaining_sync(100, 0.0), Duration::MAX);
+        assert_eq!(ledger.estimate_remaining_sync(100, f64::NAN), Duration::MAX);
+
+        // Ensure the rate is unavailable with only the genesis block.
+        assert_eq!(ledger.block_times.len(), 1);
+        assert_eq!(ledger.recent_blocks_per_second(), None);
+
+        // Ensure the rate is measured from the recorded times.
+        let start = Instant::now();
+        ledger.block_times = [start, start + Duration::from_secs(1), start + Duration::from_secs(2)].into();
+        assert_eq!(ledger.recent_blocks_per_second(), Some(1.0));
+    }
+
+    #[test]
+    fn test_state_paths() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Add a transaction to the memory pool.
+        let transaction = crate::ledger::vm::test_helpers::sample_execution_transaction(rng);
+        ledger.add_to_memory_pool(transaction).unwrap();
+        // Propose the next block.
+        let next_block = ledger.propose_next_block(&private_key, rng).unwrap();
+        // Add the next block.
+        ledger.add_next_block(&next_block).unwrap();
+
+        // Collect the commitments across both blocks.
+        let genesis = ledger.get_block(0).unwrap();
+        let commitments = genesis.transactions().commitments().chain(next_block.transactions().commitments());
+        let commitments = commitments.copied().collect::<Vec<_>>();
+        assert!(commitments.len() > 1);
+
+        // Ensure the batched state paths match the individual state paths.
+        let state_paths = ledger.to_state_paths(&commitments).unwrap();
+        assert_eq!(state_paths.len(), commitments.len());
+        for (commitment, state_path) in commitments.iter().zip_eq(&state_paths) {
+            assert_eq!(*state_path, ledger.to_state_path(commitment).unwrap());
+        }
+
+        // Ensure an empty batch is supported.
+        assert!(ledger.to_state_paths(&[]).unwrap().is_empty());
+        // Ensure a batch with an unknown commitment is rejected.
+        assert!(ledger.to_state_paths(&[Field::from_u64(1)]).is_err());
+    }
+
+    #[test]
+    fn test_verify_state_path() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis ledger.
+        let ledger = test_helpers::sample_genesis_ledger(rng);
+        // Retrieve a commitment from the genesis block.
+        let genesis = ledger.get_block(0).unwrap();
+        let commitment = *genesis.transactions().commitments().next().unwrap();
+
+        // Ensure a valid state path verifies.
+        let state_path = ledger.to_state_path(&commitment).unwrap();
+        assert!(CurrentLedger::verify_state_path(&state_path, &commitment).unwrap());
+        // Ensure the state path does not verify for a different commitment.
+        assert!(!CurrentLedger::verify_state_path(&state_path, &Field::from_u64(1)).unwrap());
+
+        // Tamper with one sibling in the transition path.
+        let transition_path = state_path.transition_path();
+        let mut siblings = transition_path.siblings().to_vec();
+        siblings[0] += Field::one();
+        let transition_path = MerklePath::try_from((transition_path.leaf_index(), siblings)).unwrap();
+        let tampered = StatePath::new_unchecked(
+            state_path.state_root(),
+            state_path.block_path().clone(),
+            state_path.block_hash(),
+            state_path.previous_block_hash(),
+            *state_path.header_root(),
+            state_path.header_path().clone(),
+            state_path.header_leaf().clone(),
+            state_path.transactions_path().clone(),
+            *state_path.transaction_id(),
+            state_path.transaction_path().clone(),
+            state_path.transaction_leaf().clone(),
+            transition_path,
+            state_path.transition_leaf().clone(),
+        );
+        // Ensure the tampered state path does not verify.
+        assert!(!CurrentLedger::verify_state_path(&tampered, &commitment).unwrap());
+    }
+
     #[test]
     #[traced_test]
     fn test_ledger_deploy() {
@@ -1047,6 +1893,73 @@ mod tests {
         assert!(ledger.add_to_memory_pool(transaction).is_err());
     }
 
+    #[test]
+    #[traced_test]
+    fn test_get_deployment_transaction() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Sample a deployment transaction.
+        let transaction = crate::ledger::vm::test_helpers::sample_deployment_transaction(rng);
+        let program_id = match &transaction {
+            Transaction::Deploy(_, deployment, _) => *deployment.program_id(),
+            _ => panic!("Expected a deployment transaction"),
+        };
+
+        // Ensure the program is not yet deployed.
+        assert!(ledger.get_deployment_transaction(&program_id).unwrap().is_none());
+
+        // Add the transaction to the memory pool.
+        ledger.add_to_memory_pool(transaction.clone()).unwrap();
+
+        // Propose the next block.
+        let next_block = ledger.propose_next_block(&private_key, rng).unwrap();
+        // Add the next block.
+        ledger.add_next_block(&next_block).unwrap();
+
+        // Ensure the deployment transaction is returned.
+        let (height, candidate) = ledger.get_deployment_transaction(&program_id).unwrap().unwrap();
+        assert_eq!(height, 1);
+        assert_eq!(candidate, transaction);
+    }
+
+    #[test]
+    #[traced_test]
+    fn test_transaction_status() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Sample a deployment transaction.
+        let transaction = crate::ledger::vm::test_helpers::sample_deployment_transaction(rng);
+        // Ensure the transaction is unknown.
+        assert_eq!(ledger.transaction_status(&transaction.id()).unwrap(), TxStatus::Unknown);
+
+        // Add the transaction to the memory pool.
+        ledger.add_to_memory_pool(transaction.clone()).unwrap();
+        // Ensure the transaction is pending.
+        assert_eq!(ledger.transaction_status(&transaction.id()).unwrap(), TxStatus::Pending);
+
+        // Propose the next block.
+        let next_block = ledger.propose_next_block(&private_key, rng).unwrap();
+        // Construct a next block.
+        ledger.add_next_block(&next_block).unwrap();
+
+        // Ensure the transaction is confirmed.
+        assert_eq!(ledger.transaction_status(&transaction.id()).unwrap(), TxStatus::Confirmed {
+            block_hash: next_block.hash(),
+            height: 1,
+            confirmations: 1
+        });
+    }
+
     #[test]
     #[traced_test]
     fn test_ledger_execute() {
@@ -1075,6 +1988,106 @@ mod tests {
         assert!(ledger.add_to_memory_pool(transaction).is_err());
     }
 
+    #[test]
+    fn test_export_import_block() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Add a transaction to the memory pool.
+        let transaction = crate::ledger::vm::test_helpers::sample_execution_transaction(rng);
+        ledger.add_to_memory_pool(transaction).unwrap();
+
+        // Propose the next block.
+        let next_block = ledger.propose_next_block(&private_key, rng).unwrap();
+        // Add the next block.
+        ledger.add_next_block(&next_block).unwrap();
+
+        // Export block 1.
+        let bytes = ledger.export_block(1).unwrap();
+
+        // Initialize a second ledger with the same genesis block.
+        let mut other_ledger = test_helpers::sample_genesis_ledger(rng);
+        assert_eq!(ledger.get_block(0).unwrap(), other_ledger.get_block(0).unwrap());
+
+        // Import block 1.
+        other_ledger.import_block(&bytes).unwrap();
+        assert_eq!(other_ledger.latest_height(), 1);
+        assert_eq!(other_ledger.latest_hash(), next_block.hash());
+        assert_eq!(other_ledger.get_block(1).unwrap(), next_block);
+
+        // Ensure the same block cannot be imported twice.
+        assert!(other_ledger.import_block(&bytes).is_err());
+        // Ensure malformed bytes are rejected.
+        assert!(other_ledger.import_block(&bytes[1..]).is_err());
+    }
+
+    #[test]
+    fn test_export_import_blocks() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Add two blocks to the ledger.
+        for _ in 0..2 {
+            // Add a transaction to the memory pool.
+            let transaction = crate::ledger::vm::test_helpers::sample_execution_transaction(rng);
+            ledger.add_to_memory_pool(transaction).unwrap();
+            // Propose the next block.
+            let next_block = ledger.propose_next_block(&private_key, rng).unwrap();
+            // Add the next block.
+            ledger.add_next_block(&next_block).unwrap();
+        }
+        assert_eq!(ledger.latest_height(), 2);
+
+        // Export the 3-block chain.
+        let mut archive = Vec::new();
+        ledger.export_blocks(&mut archive).unwrap();
+
+        // Initialize a second ledger with the same genesis block.
+        let mut other_ledger = test_helpers::sample_genesis_ledger(rng);
+        assert_eq!(ledger.get_block(0).unwrap(), other_ledger.get_block(0).unwrap());
+
+        // Import the chain.
+        assert_eq!(other_ledger.import_blocks(&archive[..]).unwrap(), 2);
+        assert_eq!(other_ledger.latest_height(), 2);
+        assert_eq!(other_ledger.latest_hash(), ledger.latest_hash());
+        for height in 0..=2 {
+            assert_eq!(other_ledger.get_block(height).unwrap(), ledger.get_block(height).unwrap());
+        }
+
+        // Ensure importing the same archive again reconciles, and imports nothing.
+        assert_eq!(other_ledger.import_blocks(&archive[..]).unwrap(), 0);
+        // Ensure a truncated archive is rejected.
+        assert!(other_ledger.import_blocks(&archive[..archive.len() - 1]).is_err());
+
+        // Ensure an archive with an oversized length prefix is rejected before allocating.
+        let mut fresh_ledger = test_helpers::sample_genesis_ledger(rng);
+        let num_bytes = u32::try_from(CurrentNetwork::MAX_BLOCK_SIZE + 1).unwrap();
+        let oversized_archive = [1u32.to_le_bytes(), num_bytes.to_le_bytes()].concat();
+        let error = fresh_ledger.import_blocks(&oversized_archive[..]).unwrap_err();
+        assert!(error.to_string().contains("exceeds the maximum block size"));
+        let oversized_archive = [1u32.to_le_bytes(), u32::MAX.to_le_bytes()].concat();
+        assert!(fresh_ledger.import_blocks(&oversized_archive[..]).is_err());
+        assert_eq!(fresh_ledger.latest_height(), 0);
+
+        // Ensure a truncated archive is rejected by a fresh ledger.
+        assert!(fresh_ledger.import_blocks(&archive[..6]).is_err());
+        assert_eq!(fresh_ledger.latest_height(), 0);
+        assert!(fresh_ledger.import_blocks(&archive[..archive.len() - 1]).is_err());
+
+        // Ensure an archive with a different genesis block is rejected.
+        let mut mismatched_ledger = CurrentLedger::new(None).unwrap();
+        assert!(mismatched_ledger.import_blocks(&archive[..]).is_err());
+        assert_eq!(mismatched_ledger.latest_height(), 0);
+    }
+
     #[test]
     #[traced_test]
     fn test_ledger_execute_many() {
@@ -1131,4 +2144,1612 @@ mod tests {
             assert_eq!(ledger.latest_hash(), next_block.hash());
         }
     }
+
+    #[test]
+    fn test_check_next_block_timestamp() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Add a transaction to the memory pool.
+        let transaction = crate::ledger::vm::test_helpers::sample_execution_transaction(rng);
+        ledger.add_to_memory_pool(transaction).unwrap();
+
+        // Propose the next block.
+        let proposed_block = ledger.propose_next_block(&private_key, rng).unwrap();
+
+        // Reconstructs the proposed block with the given timestamp.
+        let mut sample_block_with_timestamp = |timestamp: i64| {
+            let metadata = Metadata::new(
+                CurrentNetwork::ID,
+                proposed_block.round(),
+                proposed_block.height(),
+                proposed_block.coinbase_target(),
+                proposed_block.proof_target(),
+                timestamp,
+            )
+            .unwrap();
+            let header =
+                Header::from(*proposed_block.previous_state_root(), *proposed_block.transactions_root(), metadata)
+                    .unwrap();
+            Block::new(&private_key, proposed_block.previous_hash(), header, proposed_block.transactions().clone(), rng)
+                .unwrap()
+        };
+
+        // Ensure a future-dated block is rejected.
+        let max_drift =
+            i64::from(CurrentNetwork::ANCHOR_TIME) * i64::from(CurrentNetwork::MAX_FUTURE_TIMESTAMP_ANCHORS);
+        let future_timestamp = OffsetDateTime::now_utc().unix_timestamp() + max_drift + 3600;
+        let future_block = sample_block_with_timestamp(future_timestamp);
+        assert!(ledger.check_next_block(&future_block).is_err());
+
+        // Ensure a block that is not after the median timestamp is rejected.
+        let stale_block = sample_block_with_timestamp(ledger.latest_median_timestamp().unwrap());
+        assert!(ledger.check_next_block(&stale_block).is_err());
+
+        // Ensure a block with a reasonable timestamp is accepted.
+        let next_block = sample_block_with_timestamp(OffsetDateTime::now_utc().unix_timestamp());
+        ledger.check_next_block(&next_block).unwrap();
+        ledger.add_next_block(&next_block).unwrap();
+        assert_eq!(ledger.latest_height(), 1);
+    }
+
+    #[test]
+    fn test_check_next_block_median_timestamp() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key, view key, and address.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Initialize a ledger with a genesis block from 100 seconds ago.
+        let genesis_timestamp = OffsetDateTime::now_utc().unix_timestamp() - 100;
+        let vm = crate::ledger::vm::test_helpers::sample_vm();
+        let genesis = Block::genesis_with_timestamp(&vm, &private_key, genesis_timestamp, rng).unwrap();
+        let mut ledger =
+            CurrentLedger::new_with_genesis_and_timestamp(&genesis, address, genesis_timestamp, None).unwrap();
+
+        // Samples the next block with the given timestamp.
+        let mut sample_next_block = |ledger: &CurrentLedger, timestamp: i64| {
+            // Split the largest record, to include a transaction in the block.
+            let (_, record) = ledger
+                .find_records(&view_key, RecordsFilter::Unspent)
+                .unwrap()
+                .max_by_key(|(_, record)| ***record.gates())
+                .unwrap();
+            let transaction = Transaction::execute(
+                ledger.vm(),
+                &private_key,
+                &ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str("split").unwrap(),
+                &[Value::Record(record), Value::from_str("1u64").unwrap()],
+                None,
+                rng,
+            )
+            .unwrap();
+            let proposed_block = ledger.propose_next_block_with(&private_key, vec![transaction], rng).unwrap();
+
+            // Reconstruct the proposed block with the given timestamp.
+            let metadata = Metadata::new(
+                CurrentNetwork::ID,
+                proposed_block.round(),
+                proposed_block.height(),
+                proposed_block.coinbase_target(),
+                proposed_block.proof_target(),
+                timestamp,
+            )
+            .unwrap();
+            let header =
+                Header::from(*proposed_block.previous_state_root(), *proposed_block.transactions_root(), metadata)
+                    .unwrap();
+            let transactions = proposed_block.transactions().clone();
+            Block::new(&private_key, proposed_block.previous_hash(), header, transactions, rng).unwrap()
+        };
+
+        // Add 2 blocks, so that the median timestamp lags the latest timestamp.
+        ledger.add_next_block(&sample_next_block(&ledger, genesis_timestamp + 20)).unwrap();
+        ledger.add_next_block(&sample_next_block(&ledger, genesis_timestamp + 60)).unwrap();
+        assert_eq!(ledger.latest_median_timestamp().unwrap(), genesis_timestamp + 20);
+        assert_eq!(ledger.latest_timestamp().unwrap(), genesis_timestamp + 60);
+
+        // Ensure a block at the median timestamp is rejected, even though it is after the genesis timestamp.
+        let stale_block = sample_next_block(&ledger, genesis_timestamp + 20);
+        let error = ledger.check_next_block(&stale_block).unwrap_err();
+        assert!(error.to_string().contains("median timestamp"));
+
+        // Ensure a block between the median timestamp and the latest timestamp is accepted.
+        let next_block = sample_next_block(&ledger, genesis_timestamp + 40);
+        ledger.add_next_block(&next_block).unwrap();
+        assert_eq!(ledger.latest_height(), 3);
+        assert_eq!(ledger.latest_timestamp().unwrap(), genesis_timestamp + 40);
+    }
+
+    #[test]
+    fn test_check_next_block_previous_state_root() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Add a transaction to the memory pool.
+        let transaction = crate::ledger::vm::test_helpers::sample_execution_transaction(rng);
+        ledger.add_to_memory_pool(transaction).unwrap();
+
+        // Propose the next block.
+        let proposed_block = ledger.propose_next_block(&private_key, rng).unwrap();
+        assert_eq!(proposed_block.previous_state_root(), ledger.latest_state_root());
+
+        // Reconstruct the proposed block with an incorrect previous state root.
+        let header =
+            Header::from(Field::rand(rng), *proposed_block.transactions_root(), *proposed_block.metadata()).unwrap();
+        let block = Block::new(
+            &private_key,
+            proposed_block.previous_hash(),
+            header,
+            proposed_block.transactions().clone(),
+            rng,
+        )
+        .unwrap();
+
+        // Ensure the block is rejected.
+        assert!(ledger.check_next_block(&block).is_err());
+        // Ensure the proposed block is accepted.
+        ledger.check_next_block(&proposed_block).unwrap();
+    }
+
+    #[test]
+    fn test_check_next_block_duplicates() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Fetch the genesis record.
+        let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+
+        // Create two transactions that spend the same record.
+        let mut sample_split = |amount: u64| {
+            Transaction::execute(
+                ledger.vm(),
+                &private_key,
+                &ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str("split").unwrap(),
+                &[Value::Record(record.clone()), Value::from_str(&format!("{amount}u64")).unwrap()],
+                None,
+                rng,
+            )
+            .unwrap()
+        };
+        let first = sample_split(1);
+        let second = sample_split(2);
+
+        // Ensure each transaction is valid against the ledger.
+        ledger.check_transaction(&first).unwrap();
+        ledger.check_transaction(&second).unwrap();
+
+        // Propose the next block with the first transaction.
+        ledger.add_to_memory_pool(first.clone()).unwrap();
+        let proposed_block = ledger.propose_next_block(&private_key, rng).unwrap();
+
+        // Reconstruct the proposed block with both transactions.
+        let transactions = Transactions::from(&[first, second]);
+        let header = Header::from(
+            *proposed_block.previous_state_root(),
+            transactions.to_root().unwrap(),
+            *proposed_block.metadata(),
+        )
+        .unwrap();
+        let block = Block::new(&private_key, proposed_block.previous_hash(), header, transactions, rng).unwrap();
+
+        // Ensure the block is rejected for the duplicate serial number.
+        let error = ledger.check_next_block(&block).unwrap_err();
+        assert!(error.to_string().contains("duplicate serial numbers"));
+    }
+
+    #[test]
+    fn test_check_next_block_invalid_transaction() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Split the genesis record, to pay for two deployments.
+        let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+        let transaction = Transaction::execute(
+            ledger.vm(),
+            &private_key,
+            &ProgramID::from_str("credits.aleo").unwrap(),
+            Identifier::from_str("split").unwrap(),
+            &[Value::Record(record), Value::from_str("100u64").unwrap()],
+            None,
+            rng,
+        )
+        .unwrap();
+        let block = ledger.propose_next_block_with(&private_key, vec![transaction], rng).unwrap();
+        ledger.add_next_block(&block).unwrap();
+
+        // Create two deployments of the same program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program dummy.aleo;
+
+function foo:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;",
+        )
+        .unwrap();
+        let records = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().collect::<Vec<_>>();
+        let mut deployments = records
+            .into_iter()
+            .map(|(_, record)| Transaction::deploy(ledger.vm(), &private_key, &program, (record, 10), rng).unwrap());
+        let (first, second) = (deployments.next().unwrap(), deployments.next().unwrap());
+
+        // Add the first deployment.
+        let block = ledger.propose_next_block_with(&private_key, vec![first], rng).unwrap();
+        ledger.add_next_block(&block).unwrap();
+
+        // Ensure a block with the second deployment is rejected, and the error names the transaction.
+        let block = ledger.propose_next_block_with(&private_key, vec![second.clone()], rng).unwrap();
+        let error = ledger.check_next_block(&block).unwrap_err().to_string();
+        assert!(error.starts_with(&format!("Invalid transaction '{}' found in the transactions list", second.id())));
+    }
+
+    #[test]
+    fn test_add_next_block_finalize_failure() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Split the genesis record, to pay for two deployments.
+        let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+        let transaction = Transaction::execute(
+            ledger.vm(),
+            &private_key,
+            &ProgramID::from_str("credits.aleo").unwrap(),
+            Identifier::from_str("split").unwrap(),
+            &[Value::Record(record), Value::from_str("100u64").unwrap()],
+            None,
+            rng,
+        )
+        .unwrap();
+        let block = ledger.propose_next_block_with(&private_key, vec![transaction], rng).unwrap();
+        ledger.add_next_block(&block).unwrap();
+
+        // Deploy the same program twice, so that the second deployment fails to finalize.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program dummy.aleo;
+
+function foo:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;",
+        )
+        .unwrap();
+        let transactions = ledger
+            .find_records(&view_key, RecordsFilter::Unspent)
+            .unwrap()
+            .map(|(_, record)| Transaction::deploy(ledger.vm(), &private_key, &program, (record, 10), rng).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(transactions.len(), 2);
+        let block = ledger.propose_next_block_with(&private_key, transactions.clone(), rng).unwrap();
+        assert_eq!(block.transactions().len(), 2);
+
+        // Ensure the block is rejected.
+        assert!(ledger.add_next_block(&block).is_err());
+
+        // Ensure the block was not persisted.
+        assert_eq!(ledger.latest_height(), 1);
+        assert!(!ledger.contains_block_height(2).unwrap());
+        assert!(!ledger.contains_block_hash(&block.hash()).unwrap());
+        for transaction in &transactions {
+            assert!(!ledger.contains_transaction_id(&transaction.id()).unwrap());
+        }
+        // Ensure the first deployment was rolled back.
+        assert!(!ledger.vm().contains_program(program.id()));
+    }
+
+    /// An in-memory program storage, which fails to initialize its second mapping.
+    #[derive(Clone)]
+    struct FailingProgramMemory<N: Network> {
+        /// The program storage.
+        memory: ProgramMemory<N>,
+        /// The number of attempts to initialize a mapping.
+        num_mappings: Arc<AtomicUsize>,
+    }
+
+    #[rustfmt::skip]
+    impl<N: Network> ProgramStorage<N> for FailingProgramMemory<N> {
+        type ProgramIDMap = <ProgramMemory<N> as ProgramStorage<N>>::ProgramIDMap;
+        type MappingIDMap = <ProgramMemory<N> as ProgramStorage<N>>::MappingIDMap;
+        type KeyValueIDMap = <ProgramMemory<N> as ProgramStorage<N>>::KeyValueIDMap;
+        type KeyMap = <ProgramMemory<N> as ProgramStorage<N>>::KeyMap;
+        type ValueMap = <ProgramMemory<N> as ProgramStorage<N>>::ValueMap;
+
+        fn open(dev: Option<u16>) -> Result<Self> {
+            Ok(Self { memory: ProgramMemory::open(dev)?, num_mappings: Default::default() })
+        }
+
+        fn program_id_map(&self) -> &Self::ProgramIDMap {
+            self.memory.program_id_map()
+        }
+
+        fn mapping_id_map(&self) -> &Self::MappingIDMap {
+            self.memory.mapping_id_map()
+        }
+
+        fn key_value_id_map(&self) -> &Self::KeyValueIDMap {
+            self.memory.key_value_id_map()
+        }
+
+        fn key_map(&self) -> &Self::KeyMap {
+            self.memory.key_map()
+        }
+
+        fn value_map(&self) -> &Self::ValueMap {
+            self.memory.value_map()
+        }
+
+        fn dev(&self) -> Option<u16> {
+            self.memory.dev()
+        }
+
+        fn initialize_mapping(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<()> {
+            // Fail on the second attempt to initialize a mapping.
+            if self.num_mappings.fetch_add(1, Ordering::SeqCst) == 1 {
+                bail!("Failed to initialize mapping '{mapping_name}'")
+            }
+            self.memory.initialize_mapping(program_id, mapping_name)
+        }
+    }
+
+    #[test]
+    fn test_add_next_block_finalize_failure_restores_vm() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+
+        // Initialize a ledger from the genesis blocks, with a program store that fails on its second mapping.
+        let store = ProgramStore::<_, FailingProgramMemory<_>>::open(None).unwrap();
+        let mut ledger = Ledger::<CurrentNetwork, BlockMemory<_>, _>::from(
+            test_helpers::sample_genesis_ledger(rng).blocks,
+            store.clone(),
+        )
+        .unwrap();
+
+        // Split the genesis record, to pay for two deployments.
+        let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+        let transaction = Transaction::execute(
+            ledger.vm(),
+            &private_key,
+            &ProgramID::from_str("credits.aleo").unwrap(),
+            Identifier::from_str("split").unwrap(),
+            &[Value::Record(record), Value::from_str("100u64").unwrap()],
+            None,
+            rng,
+        )
+        .unwrap();
+        let block = ledger.propose_next_block_with(&private_key, vec![transaction], rng).unwrap();
+        ledger.add_next_block(&block).unwrap();
+
+        // Deploy two programs with a mapping each, so that the second deployment fails to finalize.
+        let programs = ["first.aleo", "second.aleo"].map(|program_id| {
+            Program::<CurrentNetwork>::from_str(&format!(
+                r"
+program {program_id};
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function foo:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;"
+            ))
+            .unwrap()
+        });
+        let transactions = ledger
+            .find_records(&view_key, RecordsFilter::Unspent)
+            .unwrap()
+            .zip(&programs)
+            .map(|((_, record), program)| {
+                Transaction::deploy(ledger.vm(), &private_key, program, (record, 10), rng).unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(transactions.len(), 2);
+        let block = ledger.propose_next_block_with(&private_key, transactions, rng).unwrap();
+        assert_eq!(block.transactions().len(), 2);
+
+        // Ensure the block is rejected.
+        assert!(ledger.add_next_block(&block).is_err());
+
+        // Ensure the block was not persisted.
+        assert_eq!(ledger.latest_height(), 1);
+        assert!(!ledger.contains_block_hash(&block.hash()).unwrap());
+        // Ensure the VM and the program store are unchanged.
+        for program in &programs {
+            assert!(!ledger.vm().contains_program(program.id()));
+            assert!(!store.contains_program(program.id()).unwrap());
+            assert!(store.get_mappings(program.id()).unwrap().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_blocks() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Add a transaction to the memory pool.
+        let transaction = crate::ledger::vm::test_helpers::sample_execution_transaction(rng);
+        ledger.add_to_memory_pool(transaction).unwrap();
+
+        // Add the next block.
+        let next_block = ledger.propose_next_block(&private_key, rng).unwrap();
+        ledger.add_next_block(&next_block).unwrap();
+
+        // Stream the blocks.
+        let blocks = ledger.blocks().collect::<Result<Vec<_>>>().unwrap();
+        // Ensure every block is streamed.
+        assert_eq!(blocks.len(), ledger.latest_height() as usize + 1);
+        // Ensure the blocks are in ascending height order.
+        for (height, block) in blocks.iter().enumerate() {
+            assert_eq!(block.height(), height as u32);
+        }
+        assert_eq!(blocks.last().unwrap(), &next_block);
+    }
+
+    #[test]
+    fn test_check_transaction_against_pending() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Fetch the genesis record.
+        let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+
+        // Create two transactions that spend the same record.
+        let mut sample_split = |amount: u64| {
+            Transaction::execute(
+                ledger.vm(),
+                &private_key,
+                &ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str("split").unwrap(),
+                &[Value::Record(record.clone()), Value::from_str(&format!("{amount}u64")).unwrap()],
+                None,
+                rng,
+            )
+            .unwrap()
+        };
+        let first = sample_split(1);
+        let second = sample_split(2);
+
+        // Ensure both transactions are valid independently.
+        ledger.check_transaction_against_pending(&first, &[]).unwrap();
+        ledger.check_transaction_against_pending(&second, &[]).unwrap();
+
+        // Ensure the second transaction is rejected against the first.
+        assert!(ledger.check_transaction_against_pending(&second, &[first]).is_err());
+    }
+
+    #[test]
+    fn test_reverify_memory_pool() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Fetch the genesis record.
+        let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+
+        // Create two transactions that spend the same record.
+        let mut sample_split = |amount: u64| {
+            Transaction::execute(
+                ledger.vm(),
+                &private_key,
+                &ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str("split").unwrap(),
+                &[Value::Record(record.clone()), Value::from_str(&format!("{amount}u64")).unwrap()],
+                None,
+                rng,
+            )
+            .unwrap()
+        };
+        let first = sample_split(1);
+        let second = sample_split(2);
+
+        // Ensure a valid memory pool is left untouched.
+        ledger.add_to_memory_pool(first).unwrap();
+        assert!(ledger.reverify_memory_pool().is_empty());
+        assert_eq!(ledger.memory_pool().len(), 1);
+
+        // Add a block that spends the record.
+        let next_block = ledger.propose_next_block(&private_key, rng).unwrap();
+        ledger.add_next_block(&next_block).unwrap();
+        assert!(ledger.memory_pool().is_empty());
+
+        // Simulate a memory pool that was verified against a previous ledger state (e.g. before a reorg).
+        ledger.memory_pool.insert(second.id(), second.clone());
+
+        // Ensure the stale transaction is removed.
+        assert_eq!(ledger.reverify_memory_pool(), vec![second.id()]);
+        assert!(ledger.memory_pool().is_empty());
+    }
+
+    #[test]
+    fn test_add_next_block_prunes_memory_pool() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Fetch the genesis record.
+        let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+
+        // Create two transactions that spend the same record.
+        let mut sample_split = |amount: u64| {
+            Transaction::execute(
+                ledger.vm(),
+                &private_key,
+                &ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str("split").unwrap(),
+                &[Value::Record(record.clone()), Value::from_str(&format!("{amount}u64")).unwrap()],
+                None,
+                rng,
+            )
+            .unwrap()
+        };
+        let first = sample_split(1);
+        let second = sample_split(2);
+
+        // Propose a block that spends the record.
+        ledger.add_to_memory_pool(first).unwrap();
+        let next_block = ledger.propose_next_block(&private_key, rng).unwrap();
+
+        // Replace the memory pool with the conflicting transaction, which is valid before the block.
+        ledger.memory_pool.clear();
+        ledger.memory_pool.insert(second.id(), second.clone());
+        ledger.check_transaction(&second).unwrap();
+
+        // Ensure the conflicting transaction is removed, once the block is added.
+        ledger.add_next_block(&next_block).unwrap();
+        assert!(ledger.memory_pool().is_empty());
+    }
+
+    #[test]
+    fn test_export_import_memory_pool() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Add a transaction to the memory pool.
+        let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+        let transaction = Transaction::execute(
+            ledger.vm(),
+            &private_key,
+            &ProgramID::from_str("credits.aleo").unwrap(),
+            Identifier::from_str("split").unwrap(),
+            &[Value::Record(record), Value::from_str("1u64").unwrap()],
+            None,
+            rng,
+        )
+        .unwrap();
+        ledger.add_to_memory_pool(transaction.clone()).unwrap();
+
+        // Export the memory pool.
+        let exported = ledger.export_memory_pool();
+        assert_eq!(exported, vec![transaction.clone()]);
+
+        // Import the memory pool into a fresh ledger at the same height.
+        let mut restored = test_helpers::sample_genesis_ledger(rng);
+        assert_eq!(restored.latest_hash(), ledger.latest_hash());
+        let results = restored.import_memory_pool(exported.clone());
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, transaction.id());
+        assert!(results[0].1.is_ok());
+        assert_eq!(restored.memory_pool(), ledger.memory_pool());
+
+        // Ensure a transaction that is no longer valid is dropped.
+        let block = restored.propose_next_block(&private_key, rng).unwrap();
+        restored.add_next_block(&block).unwrap();
+        let results = restored.import_memory_pool(exported);
+        assert!(results[0].1.is_err());
+        assert!(restored.memory_pool().is_empty());
+    }
+
+    #[test]
+    fn test_memory_pool_sorted() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Split the largest record three times, to obtain four records.
+        for _ in 0..3 {
+            let (_, record) = ledger
+                .find_records(&view_key, RecordsFilter::Unspent)
+                .unwrap()
+                .max_by_key(|(_, record)| ***record.gates())
+                .unwrap();
+            let transaction = Transaction::execute(
+                ledger.vm(),
+                &private_key,
+                &ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str("split").unwrap(),
+                &[Value::Record(record), Value::from_str("1000u64").unwrap()],
+                None,
+                rng,
+            )
+            .unwrap();
+            ledger.add_to_memory_pool(transaction).unwrap();
+            let next_block = ledger.propose_next_block(&private_key, rng).unwrap();
+            ledger.add_next_block(&next_block).unwrap();
+        }
+        let records = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().collect::<Vec<_>>();
+        assert_eq!(records.len(), 4);
+
+        // Create a transaction for each record, with the given fees.
+        let transactions = records
+            .into_iter()
+            .zip_eq([5u64, 10, 5, 1])
+            .map(|((_, record), fee)| {
+                Transaction::execute(
+                    ledger.vm(),
+                    &private_key,
+                    &ProgramID::from_str("credits.aleo").unwrap(),
+                    Identifier::from_str("fee").unwrap(),
+                    &[Value::Record(record), Value::from_str(&format!("{fee}u64")).unwrap()],
+                    None,
+                    rng,
+                )
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+        for transaction in &transactions {
+            ledger.add_to_memory_pool(transaction.clone()).unwrap();
+        }
+
+        // Ensure the total fees are computed.
+        let fees = transactions.iter().map(Transaction::total_fee).collect::<Vec<_>>();
+        assert_eq!(fees, vec![5, 10, 5, 1]);
+
+        // Ensure the memory pool is sorted by descending fee, with ties broken by ascending transaction ID.
+        let (first_tie, second_tie) = match *transactions[0].id() < *transactions[2].id() {
+            true => (&transactions[0], &transactions[2]),
+            false => (&transactions[2], &transactions[0]),
+        };
+        let expected = vec![&transactions[1], first_tie, second_tie, &transactions[3]];
+        assert_eq!(ledger.memory_pool_sorted(), expected);
+
+        // Ensure the proposer uses the same order.
+        let next_block = ledger.propose_next_block(&private_key, rng).unwrap();
+        let expected_ids = expected.iter().map(|transaction| transaction.id()).collect::<Vec<_>>();
+        assert_eq!(next_block.transaction_ids().copied().collect::<Vec<_>>(), expected_ids);
+    }
+
+    #[test]
+    fn test_propose_next_block_with() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Fetch the genesis record.
+        let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+
+        // Create two transactions that spend the same record.
+        let mut sample_split = |amount: u64| {
+            Transaction::execute(
+                ledger.vm(),
+                &private_key,
+                &ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str("split").unwrap(),
+                &[Value::Record(record.clone()), Value::from_str(&format!("{amount}u64")).unwrap()],
+                None,
+                rng,
+            )
+            .unwrap()
+        };
+        let first = sample_split(1);
+        let second = sample_split(2);
+
+        // Add the second transaction to the memory pool.
+        ledger.add_to_memory_pool(second.clone()).unwrap();
+
+        // Propose a block from an explicit single-transaction list.
+        let block = ledger.propose_next_block_with(&private_key, vec![first.clone()], rng).unwrap();
+        // Ensure the memory pool is ignored.
+        assert_eq!(block.transaction_ids().copied().collect::<Vec<_>>(), vec![first.id()]);
+        ledger.check_next_block(&block).unwrap();
+
+        // Ensure colliding transactions are excluded.
+        let block = ledger.propose_next_block_with(&private_key, vec![second.clone(), first], rng).unwrap();
+        assert_eq!(block.transaction_ids().copied().collect::<Vec<_>>(), vec![second.id()]);
+    }
+
+    #[test]
+    fn test_block_size_limit() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Splits the given record.
+        let sample_split = |ledger: &CurrentLedger, record, rng: &mut TestRng| {
+            Transaction::execute(
+                ledger.vm(),
+                &private_key,
+                &ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str("split").unwrap(),
+                &[Value::Record(record), Value::from_str("1u64").unwrap()],
+                None,
+                rng,
+            )
+            .unwrap()
+        };
+
+        // Split the genesis record.
+        let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+        let transaction = sample_split(&ledger, record, rng);
+        let block = ledger.propose_next_block_with(&private_key, vec![transaction], rng).unwrap();
+        ledger.add_next_block(&block).unwrap();
+
+        // Create two transactions that spend different records.
+        let records = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().collect::<Vec<_>>();
+        let transactions =
+            records.into_iter().map(|(_, record)| sample_split(&ledger, record, rng)).collect::<Vec<_>>();
+        assert_eq!(transactions.len(), 2);
+
+        // Compute the size of a block with both transactions.
+        let block = ledger.propose_next_block_with(&private_key, transactions.clone(), rng).unwrap();
+        assert_eq!(block.transactions().len(), 2);
+        let block_size = block.size_in_bytes().unwrap();
+
+        // Ensure the proposer excludes the transaction that exceeds the maximum block size.
+        let candidate =
+            ledger.propose_next_block_with_limits(&private_key, transactions.clone(), block_size - 1, 0, rng).unwrap();
+        assert_eq!(candidate.transaction_ids().copied().collect::<Vec<_>>(), vec![transactions[0].id()]);
+        assert!(candidate.size_in_bytes().unwrap() < block_size);
+
+        // Ensure the proposer includes both transactions at exactly the maximum block size.
+        let candidate = ledger.propose_next_block_with_limits(&private_key, transactions, block_size, 0, rng).unwrap();
+        assert_eq!(candidate.transactions().len(), 2);
+        assert_eq!(candidate.size_in_bytes().unwrap(), block_size);
+
+        // Ensure the checker rejects a block that exceeds the maximum block size.
+        assert!(CurrentLedger::check_block_size(&block, block_size).is_ok());
+        assert!(CurrentLedger::check_block_size(&block, block_size - 1).is_err());
+        ledger.check_next_block(&block).unwrap();
+    }
+
+    #[test]
+    fn test_check_transition_fees() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Propose a block with a fee of 5 gates.
+        let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+        let transaction = Transaction::execute(
+            ledger.vm(),
+            &private_key,
+            &ProgramID::from_str("credits.aleo").unwrap(),
+            Identifier::from_str("fee").unwrap(),
+            &[Value::Record(record), Value::from_str("5u64").unwrap()],
+            None,
+            rng,
+        )
+        .unwrap();
+        let block = ledger.propose_next_block_with(&private_key, vec![transaction], rng).unwrap();
+        assert!(block.transitions().all(|transition| *transition.fee() == 5));
+
+        // Ensure a fee at the minimum fee is accepted.
+        assert!(CurrentLedger::check_transition_fees(&block, 5).is_ok());
+        // Ensure a fee below the minimum fee is rejected.
+        let error = CurrentLedger::check_transition_fees(&block, 6).unwrap_err();
+        assert!(error.to_string().contains("which is below the minimum fee of 6"));
+
+        // Ensure the genesis block is exempt from the minimum fee.
+        let genesis = ledger.genesis_block().unwrap();
+        assert!(CurrentLedger::check_transition_fees(&genesis, i64::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_propose_next_block_minimum_fee() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Executes the given function of `credits.aleo` on the given record.
+        let sample_execution = |ledger: &CurrentLedger, function_name, record, amount, rng: &mut TestRng| {
+            Transaction::execute(
+                ledger.vm(),
+                &private_key,
+                &ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str(function_name).unwrap(),
+                &[Value::Record(record), Value::from_str(amount).unwrap()],
+                None,
+                rng,
+            )
+            .unwrap()
+        };
+
+        // Split the genesis record.
+        let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+        let transaction = sample_execution(&ledger, "split", record, "1u64", rng);
+        let block = ledger.propose_next_block_with(&private_key, vec![transaction], rng).unwrap();
+        ledger.add_next_block(&block).unwrap();
+
+        // Create two transactions, with fees of 5 gates and 7 gates.
+        let mut records = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().map(|(_, record)| record);
+        let low_fee = sample_execution(&ledger, "fee", records.next().unwrap(), "5u64", rng);
+        let high_fee = sample_execution(&ledger, "fee", records.next().unwrap(), "7u64", rng);
+        let transactions = vec![low_fee, high_fee.clone()];
+
+        // Ensure the proposer includes both transactions without a minimum fee.
+        let candidate = ledger
+            .propose_next_block_with_limits(&private_key, transactions.clone(), CurrentNetwork::MAX_BLOCK_SIZE, 0, rng)
+            .unwrap();
+        assert_eq!(candidate.transactions().len(), 2);
+
+        // Ensure the proposer excludes the transaction below the minimum fee.
+        let candidate = ledger
+            .propose_next_block_with_limits(&private_key, transactions, CurrentNetwork::MAX_BLOCK_SIZE, 6, rng)
+            .unwrap();
+        assert_eq!(candidate.transaction_ids().copied().collect::<Vec<_>>(), vec![high_fee.id()]);
+        // Ensure the proposed block passes the minimum fee check.
+        assert!(CurrentLedger::check_transition_fees(&candidate, 6).is_ok());
+    }
+
+    #[test]
+    fn test_verify_block_batch() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample two genesis ledgers, one to produce the blocks, and one to verify them.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+        let verifier = test_helpers::sample_genesis_ledger(rng);
+
+        // Produce two blocks, each splitting an unspent record.
+        let mut blocks = Vec::new();
+        for _ in 0..2 {
+            let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+            let transaction = Transaction::execute(
+                ledger.vm(),
+                &private_key,
+                &ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str("split").unwrap(),
+                &[Value::Record(record), Value::from_str("1u64").unwrap()],
+                None,
+                rng,
+            )
+            .unwrap();
+            let block = ledger.propose_next_block_with(&private_key, vec![transaction], rng).unwrap();
+            ledger.add_next_block(&block).unwrap();
+            blocks.push(block);
+        }
+
+        // Ensure the valid batch is accepted, without modifying the ledger.
+        verifier.verify_block_batch(&blocks).unwrap();
+        assert_eq!(verifier.latest_height(), 0);
+
+        // Ensure a broken chain link is rejected.
+        let broken = vec![blocks[0].clone(), blocks[0].clone()];
+        let error = verifier.verify_block_batch(&broken).unwrap_err();
+        assert_eq!(error.to_string(), format!("Block 1 ({}) has an incorrect previous block hash", blocks[0].hash()));
+
+        // Ensure a batch that does not extend the latest block is rejected.
+        let error = verifier.verify_block_batch(&blocks[1..]).unwrap_err();
+        assert_eq!(error.to_string(), format!("Block 2 ({}) has an incorrect previous block hash", blocks[1].hash()));
+    }
+
+    #[test]
+    fn test_propose_next_block_output_collisions() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Fetch the genesis record.
+        let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+
+        // Create a transaction that spends the genesis record.
+        let first = Transaction::execute(
+            ledger.vm(),
+            &private_key,
+            &ProgramID::from_str("credits.aleo").unwrap(),
+            Identifier::from_str("split").unwrap(),
+            &[Value::Record(record), Value::from_str("1u64").unwrap()],
+            None,
+            rng,
+        )
+        .unwrap();
+
+        // Create a transaction with the same outputs, but a different serial number.
+        let second = {
+            let transition = first.transitions().next().unwrap();
+            let inputs = transition
+                .inputs()
+                .iter()
+                .map(|input| match input {
+                    Input::Record(_, tag, origin) => Input::Record(Uniform::rand(rng), *tag, *origin),
+                    input => input.clone(),
+                })
+                .collect();
+            let transition = Transition::new(
+                *transition.program_id(),
+                *transition.function_name(),
+                inputs,
+                transition.outputs().to_vec(),
+                transition.finalize().clone(),
+                transition.proof().clone(),
+                *transition.tpk(),
+                *transition.tcm(),
+                *transition.fee(),
+            )
+            .unwrap();
+            Transaction::from_execution(Execution::from(CurrentNetwork::EDITION, &[transition]).unwrap(), None).unwrap()
+        };
+
+        // Ensure the transactions share an output commitment, but not an input.
+        assert!(first.input_ids().all(|input_id| !second.input_ids().any(|id| id == input_id)));
+        assert!(first.commitments().any(|commitment| second.commitments().any(|c| c == commitment)));
+
+        // Ensure only the first transaction is included.
+        let block = ledger.propose_next_block_with(&private_key, vec![first.clone(), second], rng).unwrap();
+        assert_eq!(block.transaction_ids().copied().collect::<Vec<_>>(), vec![first.id()]);
+    }
+
+    #[test]
+    fn test_propose_next_block_dependent_transactions() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Fetch the genesis record.
+        let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+
+        // Create a transaction that splits the genesis record.
+        let mut sample_split = |record: Record<CurrentNetwork, Plaintext<CurrentNetwork>>| {
+            Transaction::execute(
+                ledger.vm(),
+                &private_key,
+                &ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str("split").unwrap(),
+                &[Value::Record(record), Value::from_str("1u64").unwrap()],
+                None,
+                rng,
+            )
+            .unwrap()
+        };
+        let parent = sample_split(record);
+
+        // Create a transaction that splits the larger record created by the parent transaction.
+        let record = parent
+            .transitions()
+            .flat_map(Transition::output_records)
+            .map(|(_, record)| record.decrypt(&view_key).unwrap())
+            .max_by_key(|record| ***record.gates())
+            .unwrap();
+        let child = sample_split(record);
+
+        // Ensure the child transaction is ordered after the parent transaction.
+        let block = ledger.propose_next_block_with(&private_key, vec![child.clone(), parent.clone()], rng).unwrap();
+        assert_eq!(block.transaction_ids().copied().collect::<Vec<_>>(), vec![parent.id(), child.id()]);
+        ledger.check_next_block(&block).unwrap();
+
+        // Ensure the child transaction is excluded without the parent transaction, leaving no transactions.
+        assert!(ledger.propose_next_block_with(&private_key, vec![child.clone()], rng).is_err());
+
+        // Ensure the child transaction is accepted into the memory pool after the parent transaction.
+        assert!(ledger.add_to_memory_pool(child.clone()).is_err());
+        ledger.add_to_memory_pool(parent.clone()).unwrap();
+        ledger.add_to_memory_pool(child.clone()).unwrap();
+
+        // Ensure both transactions are included in the same block, in order.
+        let block = ledger.propose_next_block(&private_key, rng).unwrap();
+        assert_eq!(block.transaction_ids().copied().collect::<Vec<_>>(), vec![parent.id(), child.id()]);
+        ledger.add_next_block(&block).unwrap();
+        assert!(ledger.memory_pool().is_empty());
+    }
+
+    #[test]
+    fn test_find_records_for_program() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key, view key, and address.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        let address = Address::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Deploy a program that mints records, paying the fee with the genesis record.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program minter.aleo;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 0u64 r1 into r2 as token.record;
+    output r2 as token.record;",
+        )
+        .unwrap();
+        let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+        let transaction = Transaction::deploy(ledger.vm(), &private_key, &program, (record, 10), rng).unwrap();
+        let block = ledger.propose_next_block_with(&private_key, vec![transaction], rng).unwrap();
+        ledger.add_next_block(&block).unwrap();
+
+        // Mint a record.
+        let transaction = Transaction::execute(
+            ledger.vm(),
+            &private_key,
+            program.id(),
+            Identifier::from_str("mint").unwrap(),
+            &[Value::from_str(&address.to_string()).unwrap(), Value::from_str("5u64").unwrap()],
+            None,
+            rng,
+        )
+        .unwrap();
+        let block = ledger.propose_next_block_with(&private_key, vec![transaction], rng).unwrap();
+        ledger.add_next_block(&block).unwrap();
+
+        // Find the records for each program.
+        let credits_id = ProgramID::from_str("credits.aleo").unwrap();
+        let credits = ledger.find_records_for_program(&view_key, &credits_id, RecordsFilter::All).unwrap();
+        let credits = credits.map(|(commitment, _)| commitment).collect::<Vec<_>>();
+        let minted = ledger.find_records_for_program(&view_key, program.id(), RecordsFilter::All).unwrap();
+        let minted = minted.map(|(commitment, _)| commitment).collect::<Vec<_>>();
+
+        // Ensure the records are partitioned by program.
+        assert_eq!(minted.len(), 1);
+        assert!(!credits.is_empty());
+        assert!(credits.iter().all(|commitment| !minted.contains(commitment)));
+        assert_eq!(credits.len() + minted.len(), ledger.find_records(&view_key, RecordsFilter::All).unwrap().count());
+
+        // Ensure the filter is applied.
+        assert_eq!(ledger.find_records_for_program(&view_key, &credits_id, RecordsFilter::Spent).unwrap().count(), 1);
+        assert_eq!(ledger.find_records_for_program(&view_key, program.id(), RecordsFilter::Spent).unwrap().count(), 0);
+
+        // Ensure an unknown program has no records.
+        let unknown_id = ProgramID::from_str("unknown.aleo").unwrap();
+        assert_eq!(ledger.find_records_for_program(&view_key, &unknown_id, RecordsFilter::All).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_contains_state_root() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+        let genesis_state_root = *ledger.latest_state_root();
+
+        // Ensure the current state root is present, and a random state root is absent.
+        assert!(ledger.contains_state_root(&genesis_state_root.into()).unwrap());
+        assert!(!ledger.contains_state_root(&Uniform::rand(rng)).unwrap());
+
+        // Add a block.
+        let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+        let transaction = Transaction::execute(
+            ledger.vm(),
+            &private_key,
+            &ProgramID::from_str("credits.aleo").unwrap(),
+            Identifier::from_str("split").unwrap(),
+            &[Value::Record(record), Value::from_str("1u64").unwrap()],
+            None,
+            rng,
+        )
+        .unwrap();
+        let block = ledger.propose_next_block_with(&private_key, vec![transaction], rng).unwrap();
+        ledger.add_next_block(&block).unwrap();
+
+        // Ensure both the historical and the current state roots are present.
+        assert_ne!(*ledger.latest_state_root(), genesis_state_root);
+        assert!(ledger.contains_state_root(&genesis_state_root.into()).unwrap());
+        assert!(ledger.contains_state_root(&(*ledger.latest_state_root()).into()).unwrap());
+        assert!(!ledger.contains_state_root(&Uniform::rand(rng)).unwrap());
+
+        // Ensure the state roots are indexed when the ledger is loaded from storage.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+        let loaded = CurrentLedger::from(ledger.blocks.clone(), store).unwrap();
+        assert_eq!(loaded.latest_state_root(), ledger.latest_state_root());
+        assert_eq!(loaded.state_roots, ledger.state_roots);
+    }
+
+    #[test]
+    fn test_contains_commitment_with_filter() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Add a chain of blocks, by splitting the largest record in each block.
+        for _ in 0..3 {
+            let (_, record) = ledger
+                .find_records(&view_key, RecordsFilter::Unspent)
+                .unwrap()
+                .max_by_key(|(_, record)| ***record.gates())
+                .unwrap();
+            let transaction = Transaction::execute(
+                ledger.vm(),
+                &private_key,
+                &ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str("split").unwrap(),
+                &[Value::Record(record), Value::from_str("1u64").unwrap()],
+                None,
+                rng,
+            )
+            .unwrap();
+            let block = ledger.propose_next_block_with(&private_key, vec![transaction], rng).unwrap();
+            ledger.add_next_block(&block).unwrap();
+        }
+
+        // Collect the commitments across the chain.
+        let commitments = (0..=ledger.latest_height())
+            .flat_map(|height| ledger.get_transactions(height).unwrap().commitments().copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert!(commitments.len() > 3);
+
+        // Ensure the ledger, and a ledger loaded from storage, have no false negatives or false positives.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+        let loaded = CurrentLedger::from(ledger.blocks.clone(), store).unwrap();
+        for ledger in [&ledger, &loaded] {
+            assert!(commitments.iter().all(|commitment| ledger.contains_commitment(commitment).unwrap()));
+            for _ in 0..100 {
+                assert!(!ledger.contains_commitment(&Uniform::rand(rng)).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_block_for_commitment() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Ensure the genesis commitment resolves to the genesis block.
+        let (commitment, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+        assert_eq!(ledger.find_block_for_commitment(&commitment).unwrap(), ledger.genesis_block().unwrap());
+
+        // Split the genesis record.
+        let transaction = Transaction::execute(
+            ledger.vm(),
+            &private_key,
+            &ProgramID::from_str("credits.aleo").unwrap(),
+            Identifier::from_str("split").unwrap(),
+            &[Value::Record(record), Value::from_str("1u64").unwrap()],
+            None,
+            rng,
+        )
+        .unwrap();
+        let block = ledger.propose_next_block_with(&private_key, vec![transaction.clone()], rng).unwrap();
+        ledger.add_next_block(&block).unwrap();
+
+        // Ensure the new commitments resolve to the new block.
+        for commitment in transaction.commitments() {
+            assert_eq!(ledger.find_block_for_commitment(commitment).unwrap(), block);
+        }
+
+        // Ensure an unknown commitment is rejected.
+        assert!(ledger.find_block_for_commitment(&Field::rand(rng)).is_err());
+    }
+
+    #[test]
+    fn test_find_records_unspent_at_height() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Returns the commitments of the records that are unspent with the given filter.
+        let find_commitments = |ledger: &CurrentLedger, filter| {
+            let mut commitments = ledger.find_records(&view_key, filter).unwrap().map(|(c, _)| c).collect::<Vec<_>>();
+            commitments.sort();
+            commitments
+        };
+
+        // Adds a block that splits the record with the given commitment, and returns the new commitments.
+        let mut split = |ledger: &mut CurrentLedger, commitment: Field<CurrentNetwork>| {
+            let (_, record) = ledger
+                .find_records(&view_key, RecordsFilter::Unspent)
+                .unwrap()
+                .find(|(c, _)| *c == commitment)
+                .unwrap();
+            let transaction = Transaction::execute(
+                ledger.vm(),
+                &private_key,
+                &ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str("split").unwrap(),
+                &[Value::Record(record), Value::from_str("1u64").unwrap()],
+                None,
+                rng,
+            )
+            .unwrap();
+            let block = ledger.propose_next_block_with(&private_key, vec![transaction.clone()], rng).unwrap();
+            ledger.add_next_block(&block).unwrap();
+            transaction.commitments().copied().collect::<Vec<_>>()
+        };
+
+        // At height 1, split the genesis record into `a` and `b`.
+        let genesis = find_commitments(&ledger, RecordsFilter::Unspent);
+        let (a, b) = match split(&mut ledger, genesis[0])[..] {
+            [a, b] => (a, b),
+            _ => panic!("Expected two records"),
+        };
+        // At height 2, split `a`.
+        let from_a = split(&mut ledger, a);
+        // At height 3, split `b`.
+        let from_b = split(&mut ledger, b);
+        assert_eq!(ledger.latest_height(), 3);
+
+        // Ensure `b` is unspent at height 2, but spent at height 3.
+        let mut expected = [vec![b], from_a.clone()].concat();
+        expected.sort();
+        assert_eq!(find_commitments(&ledger, RecordsFilter::UnspentAtHeight(2)), expected);
+        let mut expected = [from_a, from_b].concat();
+        expected.sort();
+        assert_eq!(find_commitments(&ledger, RecordsFilter::UnspentAtHeight(3)), expected);
+
+        // Ensure only the genesis record is unspent at height 0.
+        assert_eq!(find_commitments(&ledger, RecordsFilter::UnspentAtHeight(0)), genesis);
+        // Ensure the latest height matches the live state.
+        assert_eq!(
+            find_commitments(&ledger, RecordsFilter::UnspentAtHeight(3)),
+            find_commitments(&ledger, RecordsFilter::Unspent)
+        );
+    }
+
+    #[test]
+    fn test_check_transaction_verification_cache() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis ledger.
+        let ledger = test_helpers::sample_genesis_ledger(rng);
+        // Sample a transaction.
+        let transaction = crate::ledger::vm::test_helpers::sample_execution_transaction(rng);
+
+        // Ensure the transaction is verified once across repeated checks at the same tip.
+        let num_verifications = ledger.verification_cache.num_verifications();
+        for _ in 0..3 {
+            ledger.check_transaction(&transaction).unwrap();
+        }
+        assert_eq!(ledger.verification_cache.num_verifications(), num_verifications + 1);
+
+        // Ensure the transaction is verified again after the cache is cleared.
+        ledger.clear_verification_cache();
+        ledger.check_transaction(&transaction).unwrap();
+        assert_eq!(ledger.verification_cache.num_verifications(), num_verifications + 2);
+
+        // Replace the proof of the cached transaction with the proof of a genesis transition, keeping its ID.
+        let (execution, additional_fee) = match &transaction {
+            Transaction::Execute(_, execution, additional_fee) => (execution, additional_fee),
+            _ => unreachable!("Expected an execution transaction"),
+        };
+        let transition = execution.peek().unwrap();
+        let proof = ledger.genesis_block().unwrap().transitions().next().unwrap().proof().clone();
+        let transition = Transition::new(
+            *transition.program_id(),
+            *transition.function_name(),
+            transition.inputs().to_vec(),
+            transition.outputs().to_vec(),
+            transition.finalize().clone(),
+            proof,
+            *transition.tpk(),
+            *transition.tcm(),
+            *transition.fee(),
+        )
+        .unwrap();
+        let execution = Execution::from(execution.edition(), &[transition]).unwrap();
+        let tampered = Transaction::from_execution(execution, additional_fee.clone()).unwrap();
+        assert_eq!(tampered.id(), transaction.id());
+
+        // Ensure the tampered transaction is verified, and rejected, despite the cached result for its ID.
+        assert!(ledger.check_transaction(&tampered).is_err());
+        assert_eq!(ledger.verification_cache.num_verifications(), num_verifications + 3);
+        // Ensure the cached result for the valid transaction is unaffected.
+        ledger.check_transaction(&transaction).unwrap();
+        assert_eq!(ledger.verification_cache.num_verifications(), num_verifications + 3);
+    }
+
+    #[test]
+    fn test_validate_transaction() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis ledger.
+        let ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Ensure a new transaction passes every check.
+        let transaction = crate::ledger::vm::test_helpers::sample_execution_transaction(rng);
+        assert!(ledger.validate_transaction(&transaction).is_empty());
+
+        // Retrieve a transaction that is already in the ledger.
+        let transaction = ledger.genesis_block().unwrap().transactions().values().next().unwrap().clone();
+        let transaction_id = transaction.id();
+        let commitment = *transaction.commitments().next().unwrap();
+
+        // Ensure every failing check is reported, and not only the first.
+        let errors = ledger.validate_transaction(&transaction);
+        assert!(errors.len() > 2);
+        assert_eq!(errors[0], TransactionError::DuplicateTransactionID(transaction_id));
+        assert!(errors.contains(&TransactionError::DuplicateCommitment(commitment)));
+
+        // Ensure `check_transaction` returns the first error.
+        let error = ledger.check_transaction(&transaction).unwrap_err();
+        assert_eq!(error.to_string(), format!("Transaction '{transaction_id}' already exists in the ledger"));
+    }
+
+    #[test]
+    fn test_simulate_transaction() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Initialize a ledger from the genesis blocks, with a program store that is kept by the test.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+        let ledger = CurrentLedger::from(test_helpers::sample_genesis_ledger(rng).blocks, store.clone()).unwrap();
+
+        // Create a deployment of a program with a mapping.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program simulate.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function foo:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;",
+        )
+        .unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+        let transaction = Transaction::deploy(ledger.vm(), &private_key, &program, (record, 10), rng).unwrap();
+
+        // Ensure the deployment is simulated successfully, without modifying the ledger.
+        assert!(ledger.check_transaction(&transaction).is_ok());
+        assert!(ledger.simulate_transaction(&transaction).is_ok());
+        assert!(!ledger.vm().contains_program(program.id()));
+        assert!(!store.contains_mapping(program.id(), &mapping_name).unwrap());
+
+        // Ensure the simulation does not touch an atomic batch that is in progress on the program store.
+        store.start_atomic();
+        assert!(ledger.simulate_transaction(&transaction).is_ok());
+        store.abort_atomic();
+        assert!(!store.contains_mapping(program.id(), &mapping_name).unwrap());
+
+        // Initialize the mapping in storage, which conflicts with the deployment.
+        store.initialize_mapping(program.id(), &mapping_name).unwrap();
+
+        // Ensure the deployment is still well formed and unique, but fails to finalize.
+        assert!(ledger.check_transaction(&transaction).is_ok());
+        assert!(ledger.simulate_transaction(&transaction).is_err());
+        assert!(!ledger.vm().contains_program(program.id()));
+    }
+
+    #[test]
+    fn test_transaction_rejection_reason() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Create two transactions that spend the genesis record.
+        let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+        let split = |amount: &str, rng: &mut TestRng| {
+            Transaction::execute(
+                ledger.vm(),
+                &private_key,
+                &ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str("split").unwrap(),
+                &[Value::Record(record.clone()), Value::from_str(amount).unwrap()],
+                None,
+                rng,
+            )
+            .unwrap()
+        };
+        let transaction = split("1u64", rng);
+        let double_spend = split("2u64", rng);
+        assert_eq!(ledger.transaction_rejection_reason(&transaction), None);
+
+        // Add the first transaction to the ledger.
+        let block = ledger.propose_next_block_with(&private_key, vec![transaction], rng).unwrap();
+        ledger.add_next_block(&block).unwrap();
+
+        // Ensure the double spend is rejected, as the serial number of a record input is also its input ID.
+        let serial_number = *double_spend.serial_numbers().next().unwrap();
+        let reason = ledger.transaction_rejection_reason(&double_spend);
+        assert_eq!(reason, Some(TransactionError::DuplicateInputID(serial_number)));
+        let errors = ledger.validate_transaction(&double_spend);
+        assert!(errors.contains(&TransactionError::DuplicateSerialNumber(serial_number)));
+        assert_eq!(ledger.check_transaction(&double_spend).unwrap_err().to_string(), reason.unwrap().to_string());
+
+        // Replace the proof of an execution with the proof of a genesis transition.
+        let transaction = crate::ledger::vm::test_helpers::sample_execution_transaction(rng);
+        let (execution, additional_fee) = match transaction {
+            Transaction::Execute(_, execution, additional_fee) => (execution, additional_fee),
+            _ => unreachable!("Expected an execution transaction"),
+        };
+        let transition = execution.peek().unwrap();
+        let genesis = ledger.genesis_block().unwrap();
+        let proof = genesis.transitions().next().unwrap().proof().clone();
+        let transition = Transition::new(
+            *transition.program_id(),
+            *transition.function_name(),
+            transition.inputs().to_vec(),
+            transition.outputs().to_vec(),
+            transition.finalize().clone(),
+            proof,
+            *transition.tpk(),
+            *transition.tcm(),
+            *transition.fee(),
+        )
+        .unwrap();
+        let execution = Execution::from(execution.edition(), &[transition]).unwrap();
+        let transaction = Transaction::from_execution(execution, additional_fee).unwrap();
+
+        // Ensure the transaction with an invalid proof is rejected.
+        let reason = ledger.transaction_rejection_reason(&transaction);
+        assert_eq!(reason, Some(TransactionError::Invalid(transaction.id())));
+    }
+
+    #[test]
+    fn test_find_records_bounded() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Fetch the genesis record.
+        let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+
+        // Split the genesis record into two records.
+        let transaction = Transaction::execute(
+            ledger.vm(),
+            &private_key,
+            &ProgramID::from_str("credits.aleo").unwrap(),
+            Identifier::from_str("split").unwrap(),
+            &[Value::Record(record.clone()), Value::from_str(&format!("{}u64", ***record.gates() / 2)).unwrap()],
+            None,
+            rng,
+        )
+        .unwrap();
+        ledger.add_to_memory_pool(transaction).unwrap();
+
+        // Add the next block.
+        let next_block = ledger.propose_next_block(&private_key, rng).unwrap();
+        ledger.add_next_block(&next_block).unwrap();
+
+        // Ensure the ledger contains more records than the bound.
+        let num_records = ledger.find_records(&view_key, RecordsFilter::All).unwrap().count();
+        assert_eq!(num_records, 3);
+
+        // Ensure the bounded search stops at `max_records`, and signals more records are available.
+        let (records, has_more) = ledger.find_records_bounded(&view_key, RecordsFilter::All, 2).unwrap();
+        assert_eq!(records.len(), 2);
+        assert!(has_more);
+
+        // Ensure the bounded search returns all records, and signals no more records are available.
+        let (records, has_more) = ledger.find_records_bounded(&view_key, RecordsFilter::All, num_records).unwrap();
+        assert_eq!(records.len(), num_records);
+        assert!(!has_more);
+    }
+
+    #[test]
+    fn test_rebuild_block_tree() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Add the next block.
+        let transaction = crate::ledger::vm::test_helpers::sample_execution_transaction(rng);
+        ledger.add_to_memory_pool(transaction).unwrap();
+        let next_block = ledger.propose_next_block(&private_key, rng).unwrap();
+        ledger.add_next_block(&next_block).unwrap();
+        let expected_root = *ledger.latest_state_root();
+
+        // Corrupt the block tree.
+        ledger.block_tree.append(&[Field::<CurrentNetwork>::from_u64(1).to_bits_le()]).unwrap();
+        assert_ne!(*ledger.latest_state_root(), expected_root);
+
+        // Ensure the rebuilt block tree matches the block tree of a freshly opened ledger.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+        let loaded = CurrentLedger::from(ledger.blocks.clone(), store).unwrap();
+        assert_eq!(*ledger.rebuild_block_tree().unwrap(), expected_root);
+        assert_eq!(ledger.latest_state_root(), loaded.latest_state_root());
+        assert_eq!(ledger.block_tree().leaf_hashes().unwrap(), loaded.block_tree().leaf_hashes().unwrap());
+    }
 }
@@ -0,0 +1,250 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{find::RecordsView, *};
+
+/// A read-only view of the ledger at a fixed block height.
+///
+/// The snapshot shares the underlying stores with the ledger, so taking a snapshot is cheap.
+/// Reads are bounded by the height of the snapshot, so blocks added after the snapshot was taken are not visible.
+#[derive(Clone)]
+pub struct LedgerSnapshot<N: Network, B: BlockStorage<N>> {
+    /// The block hash at the time of the snapshot.
+    latest_hash: N::BlockHash,
+    /// The block height at the time of the snapshot.
+    latest_height: u32,
+    /// The round number at the time of the snapshot.
+    latest_round: u64,
+    /// The state root at the time of the snapshot.
+    latest_state_root: Field<N>,
+    /// The block store.
+    blocks: BlockStore<N, B>,
+    /// The transaction store.
+    transactions: TransactionStore<N, B::TransactionStorage>,
+    /// The transition store.
+    transitions: TransitionStore<N, B::TransitionStorage>,
+}
+
+impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
+    /// Returns a read-only snapshot of the ledger at the latest block height.
+    pub fn snapshot(&self) -> LedgerSnapshot<N, B> {
+        LedgerSnapshot {
+            latest_hash: self.current_hash,
+            latest_height: self.current_height,
+            latest_round: self.current_round,
+            latest_state_root: *self.latest_state_root(),
+            blocks: self.blocks.clone(),
+            transactions: self.transactions.clone(),
+            transitions: self.transitions.clone(),
+        }
+    }
+}
+
+impl<N: Network, B: BlockStorage<N>> LedgerSnapshot<N, B> {
+    /// Returns the latest block hash.
+    pub const fn latest_hash(&self) -> N::BlockHash {
+        self.latest_hash
+    }
+
+    /// Returns the latest block height.
+    pub const fn latest_height(&self) -> u32 {
+        self.latest_height
+    }
+
+    /// Returns the latest round number.
+    pub const fn latest_round(&self) -> u64 {
+        self.latest_round
+    }
+
+    /// Returns the latest state root.
+    pub const fn latest_state_root(&self) -> &Field<N> {
+        &self.latest_state_root
+    }
+
+    /// Returns the latest block.
+    pub fn latest_block(&self) -> Result<Block<N>> {
+        self.get_block(self.latest_height)
+    }
+}
+
+impl<N: Network, B: BlockStorage<N>> LedgerSnapshot<N, B> {
+    /// Returns the block for the given block height.
+    pub fn get_block(&self, height: u32) -> Result<Block<N>> {
+        // Retrieve the block hash.
+        let block_hash = self.get_hash(height)?;
+        // Retrieve the block.
+        match self.blocks.get_block(&block_hash)? {
+            Some(block) => Ok(block),
+            None => bail!("Block {height} ('{block_hash}') does not exist in storage"),
+        }
+    }
+
+    /// Returns the block hash for the given block height.
+    pub fn get_hash(&self, height: u32) -> Result<N::BlockHash> {
+        // Ensure the block height is in the snapshot.
+        if height > self.latest_height {
+            bail!("Block {height} does not exist in the snapshot at block {}", self.latest_height)
+        }
+        match self.blocks.get_block_hash(height)? {
+            Some(block_hash) => Ok(block_hash),
+            None => bail!("Missing block hash for block {height}"),
+        }
+    }
+
+    /// Returns the transaction for the given transaction ID.
+    pub fn get_transaction(&self, transaction_id: N::TransactionID) -> Result<Transaction<N>> {
+        // Ensure the transaction is in the snapshot.
+        if !self.contains_transaction_id(&transaction_id)? {
+            bail!("Transaction '{transaction_id}' does not exist in the snapshot at block {}", self.latest_height)
+        }
+        match self.transactions.get_transaction(&transaction_id)? {
+            Some(transaction) => Ok(transaction),
+            None => bail!("Missing transaction for ID {transaction_id}"),
+        }
+    }
+}
+
+impl<N: Network, B: BlockStorage<N>> LedgerSnapshot<N, B> {
+    /// Returns `true` if the given block hash exists.
+    pub fn contains_block_hash(&self, block_hash: &N::BlockHash) -> Result<bool> {
+        Ok(self.find_block_height(block_hash)?.is_some())
+    }
+
+    /// Returns `true` if the given block height exists.
+    pub fn contains_block_height(&self, height: u32) -> Result<bool> {
+        Ok(height <= self.latest_height && self.blocks.contains_block_height(height)?)
+    }
+
+    /// Returns `true` if the given transaction ID exists.
+    pub fn contains_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<bool> {
+        match self.blocks.find_block_hash(transaction_id)? {
+            Some(block_hash) => self.contains_block_hash(&block_hash),
+            None => Ok(false),
+        }
+    }
+
+    /// Returns `true` if the given serial number exists.
+    pub fn contains_serial_number(&self, serial_number: &Field<N>) -> Result<bool> {
+        self.records_view().contains_serial_number(serial_number)
+    }
+
+    /// Returns `true` if the given tag exists.
+    pub fn contains_tag(&self, tag: &Field<N>) -> Result<bool> {
+        self.records_view().contains_tag(tag)
+    }
+
+    /// Returns `true` if the given commitment exists.
+    pub fn contains_commitment(&self, commitment: &Field<N>) -> Result<bool> {
+        self.records_view().contains_commitment(commitment)
+    }
+
+    /// Returns the block height for the given block hash, if the block is in the snapshot.
+    fn find_block_height(&self, block_hash: &N::BlockHash) -> Result<Option<u32>> {
+        self.records_view().find_block_height(block_hash)
+    }
+}
+
+impl<N: Network, B: BlockStorage<N>> LedgerSnapshot<N, B> {
+    /// Returns a view of the stores for finding records, which excludes blocks added after the snapshot.
+    fn records_view(&self) -> RecordsView<'_, N, B> {
+        RecordsView::new(&self.blocks, &self.transactions, &self.transitions, Some(self.latest_height))
+    }
+
+    /// Returns the records that belong to the given view key.
+    #[allow(clippy::type_complexity)]
+    pub fn find_records<'a>(
+        &'a self,
+        view_key: &'a ViewKey<N>,
+        filter: RecordsFilter<N>,
+    ) -> Result<impl 'a + Iterator<Item = (Field<N>, Record<N, Plaintext<N>>)>> {
+        self.records_view().find_records(view_key, filter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::test_helpers;
+    use console::{network::Testnet3, program::Value};
+    use snarkvm_utilities::TestRng;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_snapshot() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis private key and view key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Sample the genesis ledger.
+        let mut ledger = test_helpers::sample_genesis_ledger(rng);
+
+        // Fetch the genesis record.
+        let (commitment, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+        let serial_number =
+            Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::serial_number(private_key, commitment).unwrap();
+
+        // Take a snapshot of the genesis ledger.
+        let snapshot = ledger.snapshot();
+
+        // Add a block that spends the genesis record.
+        let transaction = Transaction::execute(
+            ledger.vm(),
+            &private_key,
+            &ProgramID::from_str("credits.aleo").unwrap(),
+            Identifier::from_str("split").unwrap(),
+            &[Value::Record(record), Value::from_str("1u64").unwrap()],
+            None,
+            rng,
+        )
+        .unwrap();
+        let block = ledger.propose_next_block_with(&private_key, vec![transaction.clone()], rng).unwrap();
+        ledger.add_next_block(&block).unwrap();
+        assert_eq!(ledger.latest_height(), 1);
+
+        // Ensure the snapshot still reports the genesis block.
+        assert_eq!(snapshot.latest_height(), 0);
+        assert_eq!(snapshot.latest_hash(), ledger.get_hash(0).unwrap());
+        assert_eq!(snapshot.latest_block().unwrap(), ledger.get_block(0).unwrap());
+        assert_ne!(snapshot.latest_state_root(), ledger.latest_state_root());
+
+        // Ensure the new block is not visible in the snapshot.
+        assert!(snapshot.get_block(1).is_err());
+        assert!(!snapshot.contains_block_height(1).unwrap());
+        assert!(!snapshot.contains_block_hash(&block.hash()).unwrap());
+        assert!(!snapshot.contains_transaction_id(&transaction.id()).unwrap());
+        assert!(snapshot.get_transaction(transaction.id()).is_err());
+        assert!(!snapshot.contains_serial_number(&serial_number).unwrap());
+        for commitment in transaction.commitments() {
+            assert!(ledger.contains_commitment(commitment).unwrap());
+            assert!(!snapshot.contains_commitment(commitment).unwrap());
+        }
+
+        // Ensure the genesis record is still unspent in the snapshot.
+        let records = snapshot.find_records(&view_key, RecordsFilter::Unspent).unwrap().collect::<Vec<_>>();
+        assert_eq!(records.iter().map(|(commitment, _)| *commitment).collect::<Vec<_>>(), vec![commitment]);
+        assert_eq!(snapshot.find_records(&view_key, RecordsFilter::Spent).unwrap().count(), 0);
+
+        // Ensure a new snapshot reports the new block.
+        let snapshot = ledger.snapshot();
+        assert_eq!(snapshot.latest_height(), 1);
+        assert_eq!(snapshot.latest_block().unwrap(), block);
+        assert!(snapshot.contains_transaction_id(&transaction.id()).unwrap());
+        assert!(snapshot.contains_serial_number(&serial_number).unwrap());
+        assert_eq!(snapshot.find_records(&view_key, RecordsFilter::Spent).unwrap().count(), 1);
+    }
+}
@@ -80,56 +80,115 @@ impl<N: Network> StatePath<N> {
         transition_path: TransitionPath<N>,
         transition_leaf: TransitionLeaf<N>,
     ) -> Result<Self> {
+        // Initialize the state path.
+        let state_path = Self::new_unchecked(
+            state_root,
+            block_path,
+            block_hash,
+            previous_block_hash,
+            header_root,
+            header_path,
+            header_leaf,
+            transactions_path,
+            transaction_id,
+            transaction_path,
+            transaction_leaf,
+            transition_path,
+            transition_leaf,
+        );
+        // Ensure the state path is valid.
+        state_path.verify()?;
+        // Return the state path.
+        Ok(state_path)
+    }
+
+    /// Initializes a new instance of `StatePath`, without checking its Merkle paths.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) const fn new_unchecked(
+        state_root: N::StateRoot,
+        block_path: BlockPath<N>,
+        block_hash: N::BlockHash,
+        previous_block_hash: N::BlockHash,
+        header_root: Field<N>,
+        header_path: HeaderPath<N>,
+        header_leaf: HeaderLeaf<N>,
+        transactions_path: TransactionsPath<N>,
+        transaction_id: N::TransactionID,
+        transaction_path: TransactionPath<N>,
+        transaction_leaf: TransactionLeaf<N>,
+        transition_path: TransitionPath<N>,
+        transition_leaf: TransitionLeaf<N>,
+    ) -> Self {
+        Self {
+            state_root,
+            block_path,
+            block_hash,
+            previous_block_hash,
+            header_root,
+            header_path,
+            header_leaf,
+            transactions_path,
+            transaction_id,
+            transaction_path,
+            transaction_leaf,
+            transition_path,
+            transition_leaf,
+        }
+    }
+
+    /// Ensures every Merkle path in the state path composes up to the state root.
+    pub fn verify(&self) -> Result<()> {
+        let Self {
+            state_root,
+            block_path,
+            block_hash,
+            previous_block_hash,
+            header_root,
+            header_path,
+            header_leaf,
+            transactions_path,
+            transaction_id,
+            transaction_path,
+            transaction_leaf,
+            transition_path,
+            transition_leaf,
+        } = self;
+
         // Ensure the transition path is valid.
         ensure!(
-            N::verify_merkle_path_bhp(&transition_path, &transaction_leaf.id(), &transition_leaf.to_bits_le()),
+            N::verify_merkle_path_bhp(transition_path, &transaction_leaf.id(), &transition_leaf.to_bits_le()),
             "'{}' (an input or output ID) does not belong to '{}' (a function or transition)",
             transition_leaf.id(),
             transaction_leaf.id()
         );
         // Ensure the transaction path is valid.
         ensure!(
-            N::verify_merkle_path_bhp(&transaction_path, &transaction_id, &transaction_leaf.to_bits_le()),
+            N::verify_merkle_path_bhp(transaction_path, transaction_id, &transaction_leaf.to_bits_le()),
             "'{}' (a function or transition) does not belong to transaction '{transaction_id}'",
             transaction_leaf.id(),
         );
         // Ensure the transactions path is valid.
         ensure!(
-            N::verify_merkle_path_bhp(&transactions_path, &header_leaf.id(), &transaction_id.to_bits_le()),
+            N::verify_merkle_path_bhp(transactions_path, &header_leaf.id(), &transaction_id.to_bits_le()),
             "Transaction '{transaction_id}' does not belong to '{header_leaf}' (a header leaf)",
         );
         // Ensure the header path is valid.
         ensure!(
-            N::verify_merkle_path_bhp(&header_path, &header_root, &header_leaf.to_bits_le()),
+            N::verify_merkle_path_bhp(header_path, header_root, &header_leaf.to_bits_le()),
             "'{header_leaf}' (a header leaf) does not belong to '{block_hash}' (a block header)",
         );
         // Ensure the block hash is correct.
-        let preimage = (*previous_block_hash).to_bits_le().into_iter().chain(header_root.to_bits_le().into_iter());
+        let preimage = (**previous_block_hash).to_bits_le().into_iter().chain(header_root.to_bits_le());
         ensure!(
-            *block_hash == N::hash_bhp1024(&preimage.collect::<Vec<_>>())?,
+            **block_hash == N::hash_bhp1024(&preimage.collect::<Vec<_>>())?,
             "Block hash '{block_hash}' is incorrect. Double-check the previous block hash and block header root."
         );
         // Ensure the state root is correct.
         ensure!(
-            N::verify_merkle_path_bhp(&block_path, &state_root, &block_hash.to_bits_le()),
+            N::verify_merkle_path_bhp(block_path, state_root, &block_hash.to_bits_le()),
             "'{block_hash}' (a block hash) does not belong to '{state_root}' (a state root)",
         );
-        // Return the state path.
-        Ok(Self {
-            state_root,
-            block_path,
-            block_hash,
-            previous_block_hash,
-            header_root,
-            header_path,
-            header_leaf,
-            transactions_path,
-            transaction_id,
-            transaction_path,
-            transaction_leaf,
-            transition_path,
-            transition_leaf,
-        })
+        Ok(())
     }
 
     /// Returns the state root.
@@ -27,6 +27,9 @@ use crate::{
             TransitionMemory,
             TransitionStorage,
             TransitionStore,
+            ValidatorMemory,
+            ValidatorStorage,
+            ValidatorStore,
         },
         Block,
         Header,
@@ -65,6 +68,8 @@ pub trait BlockStorage<N: Network>: Clone + Send + Sync {
     type TransitionStorage: TransitionStorage<N>;
     /// The mapping of `block hash` to `block signature`.
     type SignatureMap: for<'a> Map<'a, N::BlockHash, Signature<N>>;
+    /// The validator storage.
+    type ValidatorStorage: ValidatorStorage<N>;
 
     /// Initializes the block storage.
     fn open(dev: Option<u16>) -> Result<Self>;
@@ -83,6 +88,8 @@ pub trait BlockStorage<N: Network>: Clone + Send + Sync {
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage>;
     /// Returns the signature map.
     fn signature_map(&self) -> &Self::SignatureMap;
+    /// Returns the validator store.
+    fn validator_store(&self) -> &ValidatorStore<N, Self::ValidatorStorage>;
 
     /// Returns the transition store.
     fn transition_store(&self) -> &TransitionStore<N, Self::TransitionStorage> {
@@ -336,6 +343,8 @@ pub struct BlockMemory<N: Network> {
     transaction_store: TransactionStore<N, TransactionMemory<N>>,
     /// The signature map.
     signature_map: MemoryMap<N::BlockHash, Signature<N>>,
+    /// The validator store.
+    validator_store: ValidatorStore<N, ValidatorMemory<N>>,
 }
 
 #[rustfmt::skip]
@@ -348,6 +357,7 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     type TransactionStorage = TransactionMemory<N>;
     type TransitionStorage = TransitionMemory<N>;
     type SignatureMap = MemoryMap<N::BlockHash, Signature<N>>;
+    type ValidatorStorage = ValidatorMemory<N>;
 
     /// Initializes the block storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -355,6 +365,8 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
         let transition_store = TransitionStore::<N, TransitionMemory<N>>::open(dev)?;
         // Initialize the transaction store.
         let transaction_store = TransactionStore::<N, TransactionMemory<N>>::open(transition_store)?;
+        // Initialize the validator store.
+        let validator_store = ValidatorStore::<N, ValidatorMemory<N>>::open(dev)?;
         // Return the block storage.
         Ok(Self {
             id_map: MemoryMap::default(),
@@ -364,6 +376,7 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
             reverse_transactions_map: MemoryMap::default(),
             transaction_store,
             signature_map: MemoryMap::default(),
+            validator_store,
         })
     }
 
@@ -401,6 +414,11 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     fn signature_map(&self) -> &Self::SignatureMap {
         &self.signature_map
     }
+
+    /// Returns the validator store.
+    fn validator_store(&self) -> &ValidatorStore<N, Self::ValidatorStorage> {
+        &self.validator_store
+    }
 }
 
 /// The block store.
@@ -446,6 +464,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.transaction_store().transition_store()
     }
 
+    /// Returns the validator store.
+    pub fn validator_store(&self) -> &ValidatorStore<N, B::ValidatorStorage> {
+        self.storage.validator_store()
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
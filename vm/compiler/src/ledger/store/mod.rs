@@ -26,6 +26,9 @@ pub use transaction::*;
 mod transition;
 pub use transition::*;
 
+mod validator;
+pub use validator::*;
+
 #[macro_export]
 macro_rules! cow_to_copied {
     ($cow:expr) => {
@@ -31,6 +31,9 @@ use core::marker::PhantomData;
 use indexmap::{IndexMap, IndexSet};
 use std::collections::BTreeMap;
 
+/// The mappings of a program, as each mapping name with its `(key, value)` pairs in insertion order.
+pub type ProgramMappings<N> = Vec<(Identifier<N>, Vec<(Plaintext<N>, Value<N>)>)>;
+
 /// A trait for program state storage. Note: For the program logic, see `DeploymentStorage`.
 ///
 /// We define the `mapping ID := Hash( program ID || mapping name )`,
@@ -659,6 +662,47 @@ impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
     }
+
+    /// Returns a copy of the program store in memory, which does not share any state with this store.
+    /// Note: Writes in an atomic batch that is in progress are not included in the copy.
+    pub fn to_memory(&self) -> Result<ProgramStore<N, ProgramMemory<N>>> {
+        // Initialize the program memory.
+        let storage = ProgramMemory::open(self.dev())?;
+        // Copy each map into the program memory.
+        for (program_id, mapping_names) in self.storage.program_id_map().iter() {
+            storage.program_id_map().insert(*program_id, mapping_names.into_owned())?;
+        }
+        for (mapping, mapping_id) in self.storage.mapping_id_map().iter() {
+            storage.mapping_id_map().insert(*mapping, *mapping_id)?;
+        }
+        for (mapping_id, key_value_ids) in self.storage.key_value_id_map().iter() {
+            storage.key_value_id_map().insert(*mapping_id, key_value_ids.into_owned())?;
+        }
+        for (key_id, key) in self.storage.key_map().iter() {
+            storage.key_map().insert(*key_id, key.into_owned())?;
+        }
+        for (key_id, value) in self.storage.value_map().iter() {
+            storage.value_map().insert(*key_id, value.into_owned())?;
+        }
+        Ok(ProgramStore::from(storage))
+    }
+
+    /// Replaces the mappings of the given `program ID` with the given mappings.
+    /// This is used to restore the mappings returned by `get_mappings`.
+    pub(crate) fn restore_mappings(&self, program_id: &ProgramID<N>, mappings: ProgramMappings<N>) -> Result<()> {
+        // Remove the current mappings, if the program ID exists.
+        if self.storage.contains_program(program_id)? {
+            self.storage.remove_program(program_id)?;
+        }
+        // Initialize each mapping, and insert its `(key, value)` pairs.
+        for (mapping_name, key_values) in mappings {
+            self.storage.initialize_mapping(program_id, &mapping_name)?;
+            for (key, value) in key_values {
+                self.storage.insert_key_value(program_id, &mapping_name, key, value)?;
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
@@ -698,6 +742,40 @@ impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
     ) -> Result<Option<Value<N>>> {
         self.storage.get_value(program_id, mapping_name, key)
     }
+
+    /// Returns the mappings for the given `program ID`, with their `(key, value)` pairs in insertion order.
+    /// If the program ID does not exist, an empty list is returned.
+    pub fn get_mappings(&self, program_id: &ProgramID<N>) -> Result<ProgramMappings<N>> {
+        // Retrieve the mapping names.
+        let mapping_names = match self.storage.get_mapping_names(program_id)? {
+            Some(mapping_names) => mapping_names,
+            None => return Ok(Vec::new()),
+        };
+        mapping_names
+            .into_iter()
+            .map(|mapping_name| {
+                // Retrieve the mapping ID.
+                let mapping_id = match self.storage.get_mapping_id(program_id, &mapping_name)? {
+                    Some(mapping_id) => mapping_id,
+                    None => bail!("Missing mapping ID for '{program_id}/{mapping_name}'"),
+                };
+                // Retrieve the key-value IDs for the mapping ID.
+                let key_value_ids = match self.storage.key_value_id_map().get(&mapping_id)? {
+                    Some(key_value_ids) => cow_to_cloned!(key_value_ids),
+                    None => bail!("Missing key-value IDs for '{program_id}/{mapping_name}'"),
+                };
+                // Retrieve the `(key, value)` pairs.
+                let key_values = key_value_ids
+                    .keys()
+                    .map(|key_id| match (self.storage.get_key(key_id)?, self.storage.get_value_from_key_id(key_id)?) {
+                        (Some(key), Some(value)) => Ok((key, value)),
+                        _ => bail!("Missing key or value for '{program_id}/{mapping_name}'"),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok((mapping_name, key_values))
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -1170,4 +1248,85 @@ mod tests {
         check_initialize_insert_remove(&program_store, program_id, mapping_name);
         check_initialize_update_remove(&program_store, program_id, mapping_name);
     }
+
+    #[test]
+    fn test_get_and_restore_mappings() {
+        // Initialize a program ID, two mapping names, and two keys.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let other_mapping_name = Identifier::from_str("balance").unwrap();
+        let key = Plaintext::from_str("123456789field").unwrap();
+        let other_key = Plaintext::from_str("987654321field").unwrap();
+        let value = Value::<CurrentNetwork>::from_str("987654321u128").unwrap();
+
+        // Initialize a new program store, with a mapping and two keys.
+        let program_store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+        assert!(program_store.get_mappings(&program_id).unwrap().is_empty());
+        program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        program_store.insert_key_value(&program_id, &mapping_name, key.clone(), value.clone()).unwrap();
+        program_store.insert_key_value(&program_id, &mapping_name, other_key.clone(), value.clone()).unwrap();
+        let checksum = program_store.storage.get_checksum().unwrap();
+
+        // Ensure the mappings are returned in insertion order.
+        let mappings = program_store.get_mappings(&program_id).unwrap();
+        let expected = vec![(mapping_name, vec![(key.clone(), value.clone()), (other_key.clone(), value.clone())])];
+        assert_eq!(mappings, expected);
+
+        // Modify the program, and ensure the mappings are restored.
+        program_store.remove_key_value(&program_id, &mapping_name, &key).unwrap();
+        program_store.initialize_mapping(&program_id, &other_mapping_name).unwrap();
+        program_store.restore_mappings(&program_id, mappings).unwrap();
+        assert_eq!(program_store.get_mappings(&program_id).unwrap(), expected);
+        assert!(!program_store.contains_mapping(&program_id, &other_mapping_name).unwrap());
+        assert_eq!(program_store.storage.get_checksum().unwrap(), checksum);
+
+        // Ensure restoring no mappings removes the program.
+        program_store.restore_mappings(&program_id, vec![]).unwrap();
+        assert!(!program_store.contains_program(&program_id).unwrap());
+        assert!(program_store.get_mappings(&program_id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_to_memory() {
+        // Initialize a program ID, two mapping names, and two keys.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let other_mapping_name = Identifier::from_str("balance").unwrap();
+        let key = Plaintext::from_str("123456789field").unwrap();
+        let new_key = Plaintext::from_str("987654321field").unwrap();
+        let value = Value::<CurrentNetwork>::from_str("987654321u128").unwrap();
+
+        // Initialize a new program store, with a mapping and a key.
+        let program_store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+        program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        program_store.insert_key_value(&program_id, &mapping_name, key.clone(), value.clone()).unwrap();
+
+        // Ensure the copy contains the mapping and the key.
+        let copy = program_store.to_memory().unwrap();
+        assert!(copy.contains_mapping(&program_id, &mapping_name).unwrap());
+        assert_eq!(copy.get_value(&program_id, &mapping_name, &key).unwrap(), Some(value.clone()));
+
+        // Ensure a write to a key in the original store conflicts in the copy.
+        assert!(copy.insert_key_value(&program_id, &mapping_name, key.clone(), value.clone()).is_err());
+
+        // Ensure two writes to the same new key conflict in the copy.
+        copy.insert_key_value(&program_id, &mapping_name, new_key.clone(), value.clone()).unwrap();
+        assert!(copy.insert_key_value(&program_id, &mapping_name, new_key.clone(), value.clone()).is_err());
+
+        // Ensure a second mapping in the copy sees the first mapping.
+        copy.initialize_mapping(&program_id, &other_mapping_name).unwrap();
+        let mapping_names = copy.get_mapping_names(&program_id).unwrap().unwrap();
+        assert_eq!(mapping_names.into_iter().collect::<Vec<_>>(), vec![mapping_name, other_mapping_name]);
+
+        // Ensure the writes to the copy are not in the original store.
+        assert!(!program_store.contains_key(&program_id, &mapping_name, &new_key).unwrap());
+        assert!(!program_store.contains_mapping(&program_id, &other_mapping_name).unwrap());
+
+        // Ensure the copy can be taken while an atomic batch is in progress, without its writes.
+        program_store.start_atomic();
+        program_store.insert_key_value(&program_id, &mapping_name, new_key.clone(), value).unwrap();
+        let copy = program_store.to_memory().unwrap();
+        assert!(!copy.contains_key(&program_id, &mapping_name, &new_key).unwrap());
+        program_store.abort_atomic();
+    }
 }
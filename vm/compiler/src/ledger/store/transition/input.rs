@@ -453,6 +453,15 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
     pub fn find_transition_id(&self, input_id: &Field<N>) -> Result<Option<N::TransitionID>> {
         self.storage.find_transition_id(input_id)
     }
+
+    /// Returns the serial number for the given `tag`.
+    pub fn find_serial_number(&self, tag: &Field<N>) -> Result<Option<Field<N>>> {
+        match self.record_tag.get(tag)? {
+            Some(Cow::Borrowed(serial_number)) => Ok(Some(*serial_number)),
+            Some(Cow::Owned(serial_number)) => Ok(Some(serial_number)),
+            None => Ok(None),
+        }
+    }
 }
 
 impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
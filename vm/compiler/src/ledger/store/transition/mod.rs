@@ -528,6 +528,11 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         // Throw an error.
         bail!("Failed to find the transition ID for the given input or output ID '{id}'")
     }
+
+    /// Returns the serial number for the given `tag`.
+    pub fn find_serial_number(&self, tag: &Field<N>) -> Result<Option<Field<N>>> {
+        self.inputs.find_serial_number(tag)
+    }
 }
 
 impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
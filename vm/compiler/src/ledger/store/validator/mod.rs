@@ -0,0 +1,207 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ledger::map::{memory_map::MemoryMap, Map, MapRead};
+use console::{account::Address, network::prelude::*};
+
+use anyhow::Result;
+use core::marker::PhantomData;
+use std::borrow::Cow;
+
+/// A trait for validator storage.
+pub trait ValidatorStorage<N: Network>: Clone + Send + Sync {
+    /// The mapping of `address` to `()`.
+    type ValidatorsMap: for<'a> Map<'a, Address<N>, ()>;
+
+    /// Initializes the validator storage.
+    fn open(dev: Option<u16>) -> Result<Self>;
+
+    /// Returns the validators map.
+    fn validators_map(&self) -> &Self::ValidatorsMap;
+
+    /// Returns the optional development ID.
+    fn dev(&self) -> Option<u16>;
+
+    /// Starts an atomic batch write operation.
+    fn start_atomic(&self) {
+        self.validators_map().start_atomic();
+    }
+
+    /// Checks if an atomic batch is in progress.
+    fn is_atomic_in_progress(&self) -> bool {
+        self.validators_map().is_atomic_in_progress()
+    }
+
+    /// Aborts an atomic batch write operation.
+    fn abort_atomic(&self) {
+        self.validators_map().abort_atomic();
+    }
+
+    /// Finishes an atomic batch write operation.
+    fn finish_atomic(&self) -> Result<()> {
+        self.validators_map().finish_atomic()
+    }
+
+    /// Stores the given `address` into storage.
+    fn insert(&self, address: Address<N>) -> Result<()> {
+        self.validators_map().insert(address, ())
+    }
+
+    /// Removes the given `address` from storage.
+    fn remove(&self, address: &Address<N>) -> Result<()> {
+        self.validators_map().remove(address)
+    }
+}
+
+/// An in-memory validator storage.
+#[derive(Clone)]
+pub struct ValidatorMemory<N: Network> {
+    /// The mapping of `address` to `()`.
+    validators_map: MemoryMap<Address<N>, ()>,
+    /// The optional development ID.
+    dev: Option<u16>,
+}
+
+#[rustfmt::skip]
+impl<N: Network> ValidatorStorage<N> for ValidatorMemory<N> {
+    type ValidatorsMap = MemoryMap<Address<N>, ()>;
+
+    /// Initializes the validator storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        Ok(Self { validators_map: MemoryMap::default(), dev })
+    }
+
+    /// Returns the validators map.
+    fn validators_map(&self) -> &Self::ValidatorsMap {
+        &self.validators_map
+    }
+
+    /// Returns the optional development ID.
+    fn dev(&self) -> Option<u16> {
+        self.dev
+    }
+}
+
+/// The validator store.
+#[derive(Clone)]
+pub struct ValidatorStore<N: Network, V: ValidatorStorage<N>> {
+    /// The validator storage.
+    storage: V,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network, V: ValidatorStorage<N>> ValidatorStore<N, V> {
+    /// Initializes the validator store.
+    pub fn open(dev: Option<u16>) -> Result<Self> {
+        // Initialize the validator storage.
+        let storage = V::open(dev)?;
+        // Return the validator store.
+        Ok(Self { storage, _phantom: PhantomData })
+    }
+
+    /// Initializes a validator store from storage.
+    pub fn from(storage: V) -> Self {
+        Self { storage, _phantom: PhantomData }
+    }
+
+    /// Stores the given `address` into storage.
+    pub fn insert(&self, address: Address<N>) -> Result<()> {
+        self.storage.insert(address)
+    }
+
+    /// Removes the given `address` from storage.
+    pub fn remove(&self, address: &Address<N>) -> Result<()> {
+        self.storage.remove(address)
+    }
+
+    /// Starts an atomic batch write operation.
+    pub fn start_atomic(&self) {
+        self.storage.start_atomic();
+    }
+
+    /// Checks if an atomic batch is in progress.
+    pub fn is_atomic_in_progress(&self) -> bool {
+        self.storage.is_atomic_in_progress()
+    }
+
+    /// Aborts an atomic batch write operation.
+    pub fn abort_atomic(&self) {
+        self.storage.abort_atomic();
+    }
+
+    /// Finishes an atomic batch write operation.
+    pub fn finish_atomic(&self) -> Result<()> {
+        self.storage.finish_atomic()
+    }
+
+    /// Returns the optional development ID.
+    pub fn dev(&self) -> Option<u16> {
+        self.storage.dev()
+    }
+}
+
+impl<N: Network, V: ValidatorStorage<N>> ValidatorStore<N, V> {
+    /// Returns `true` if the given address is a validator.
+    pub fn contains_validator(&self, address: &Address<N>) -> Result<bool> {
+        self.storage.validators_map().contains_key(address)
+    }
+}
+
+impl<N: Network, V: ValidatorStorage<N>> ValidatorStore<N, V> {
+    /// Returns an iterator over the validator addresses, for all validators in `self`.
+    pub fn validators(&self) -> impl '_ + Iterator<Item = Cow<'_, Address<N>>> {
+        self.storage.validators_map().keys()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_insert_contains_remove() {
+        let rng = &mut TestRng::default();
+
+        // Sample the address.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Initialize a new validator store.
+        let validator_store = ValidatorStore::<_, ValidatorMemory<_>>::open(None).unwrap();
+
+        // Ensure the validator does not exist.
+        assert!(!validator_store.contains_validator(&address).unwrap());
+        assert_eq!(0, validator_store.validators().count());
+
+        // Insert the validator.
+        validator_store.insert(address).unwrap();
+
+        // Ensure the validator exists.
+        assert!(validator_store.contains_validator(&address).unwrap());
+        assert_eq!(vec![address], validator_store.validators().map(|address| *address).collect::<Vec<_>>());
+
+        // Remove the validator.
+        validator_store.remove(&address).unwrap();
+
+        // Ensure the validator does not exist.
+        assert!(!validator_store.contains_validator(&address).unwrap());
+        assert_eq!(0, validator_store.validators().count());
+    }
+}
@@ -218,6 +218,11 @@ impl<N: Network> Transaction<N> {
     pub fn fees(&self) -> impl '_ + Iterator<Item = &i64> {
         self.transitions().map(Transition::fee)
     }
+
+    /// Returns the total fee, summed over all transitions.
+    pub fn total_fee(&self) -> i64 {
+        self.fees().fold(0i64, |total, fee| total.saturating_add(*fee))
+    }
 }
 
 impl<N: Network> Transaction<N> {
@@ -0,0 +1,194 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Transaction;
+use console::{network::prelude::*, types::Field};
+
+use indexmap::IndexMap;
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+/// The maximum number of verification results in the cache.
+const MAX_VERIFICATION_CACHE_SIZE: usize = 4096;
+
+/// A least-recently-used cache of transaction verification results, which is cleared when the ledger tip changes.
+///
+/// The results are keyed on a hash of the serialized transaction, rather than the transaction ID,
+/// as the transaction ID does not commit to the proofs, transition public keys and commitments,
+/// fees, finalize inputs, or verifying keys. A copy of a verified transaction with the same ID,
+/// but a tampered proof, must not reuse the cached result.
+#[derive(Clone)]
+pub(super) struct VerificationCache<N: Network> {
+    /// The cache state.
+    state: Arc<Mutex<CacheState<N>>>,
+}
+
+struct CacheState<N: Network> {
+    /// The block hash of the ledger tip for the cached results.
+    tip: Option<N::BlockHash>,
+    /// The mapping of transaction hashes to verification results, from least to most recently used.
+    results: IndexMap<Field<N>, bool>,
+    /// The number of verifications performed.
+    #[cfg(test)]
+    num_verifications: usize,
+}
+
+impl<N: Network> Default for VerificationCache<N> {
+    /// Initializes an empty cache.
+    fn default() -> Self {
+        let state = CacheState {
+            tip: None,
+            results: IndexMap::new(),
+            #[cfg(test)]
+            num_verifications: 0,
+        };
+        Self { state: Arc::new(Mutex::new(state)) }
+    }
+}
+
+impl<N: Network> VerificationCache<N> {
+    /// Returns the verification result for the given transaction at the given ledger tip.
+    /// If the result is not cached, the given verification is performed, and its result is cached.
+    pub(super) fn verify_transaction(
+        &self,
+        tip: N::BlockHash,
+        transaction: &Transaction<N>,
+        verify: impl FnOnce() -> bool,
+    ) -> bool {
+        match Self::hash_transaction(transaction) {
+            Ok(transaction_hash) => self.verify(tip, transaction_hash, verify),
+            // If the transaction cannot be hashed, perform the verification without the cache.
+            Err(_) => verify(),
+        }
+    }
+
+    /// Returns the verification result for the given transaction hash at the given ledger tip.
+    /// If the result is not cached, the given verification is performed, and its result is cached.
+    fn verify(&self, tip: N::BlockHash, transaction_hash: Field<N>, verify: impl FnOnce() -> bool) -> bool {
+        {
+            let mut state = self.state.lock();
+            // If the ledger tip changed, clear the cache.
+            if state.tip != Some(tip) {
+                state.tip = Some(tip);
+                state.results.clear();
+            }
+            // If the result is cached, mark it as the most recently used, and return it.
+            if let Some(is_valid) = state.results.shift_remove(&transaction_hash) {
+                state.results.insert(transaction_hash, is_valid);
+                return is_valid;
+            }
+        }
+
+        // Perform the verification, without holding the lock.
+        let is_valid = verify();
+
+        let mut state = self.state.lock();
+        #[cfg(test)]
+        {
+            state.num_verifications += 1;
+        }
+        // Cache the result, if the ledger tip is unchanged.
+        if state.tip == Some(tip) {
+            state.results.insert(transaction_hash, is_valid);
+            // Evict the least recently used result, if the cache is full.
+            if state.results.len() > MAX_VERIFICATION_CACHE_SIZE {
+                state.results.shift_remove_index(0);
+            }
+        }
+        is_valid
+    }
+
+    /// Returns the hash of the serialized transaction.
+    fn hash_transaction(transaction: &Transaction<N>) -> Result<Field<N>> {
+        N::hash_bhp1024(&transaction.to_bytes_le()?.to_bits_le())
+    }
+
+    /// Clears the cache.
+    pub(super) fn clear(&self) {
+        self.state.lock().results.clear();
+    }
+
+    /// Returns the number of verifications performed.
+    #[cfg(test)]
+    pub(super) fn num_verifications(&self) -> usize {
+        self.state.lock().num_verifications
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_verification_cache() {
+        let rng = &mut TestRng::default();
+
+        // Sample the ledger tips and transaction hashes.
+        let (tip_a, tip_b) = (Uniform::rand(rng), Uniform::rand(rng));
+        let (id_a, id_b) = (Uniform::rand(rng), Uniform::rand(rng));
+
+        let cache = VerificationCache::<CurrentNetwork>::default();
+
+        // Ensure the result is verified once, and then cached.
+        assert!(cache.verify(tip_a, id_a, || true));
+        assert!(cache.verify(tip_a, id_a, || panic!("The result should be cached")));
+        assert_eq!(cache.num_verifications(), 1);
+
+        // Ensure invalid results are cached.
+        assert!(!cache.verify(tip_a, id_b, || false));
+        assert!(!cache.verify(tip_a, id_b, || panic!("The result should be cached")));
+        assert_eq!(cache.num_verifications(), 2);
+
+        // Ensure the cache is invalidated when the ledger tip changes.
+        assert!(cache.verify(tip_b, id_a, || true));
+        assert_eq!(cache.num_verifications(), 3);
+        assert!(!cache.verify(tip_b, id_b, || false));
+        assert_eq!(cache.num_verifications(), 4);
+
+        // Ensure the cache is invalidated when cleared.
+        cache.clear();
+        assert!(cache.verify(tip_b, id_a, || true));
+        assert_eq!(cache.num_verifications(), 5);
+    }
+
+    #[test]
+    fn test_verification_cache_eviction() {
+        let rng = &mut TestRng::default();
+
+        let tip = Uniform::rand(rng);
+        let ids = (0..=MAX_VERIFICATION_CACHE_SIZE).map(|_| Uniform::rand(rng)).collect::<Vec<_>>();
+
+        let cache = VerificationCache::<CurrentNetwork>::default();
+
+        // Fill the cache, and use the first ID again, so that the second ID is the least recently used.
+        for id in &ids[..MAX_VERIFICATION_CACHE_SIZE] {
+            cache.verify(tip, *id, || true);
+        }
+        cache.verify(tip, ids[0], || panic!("The result should be cached"));
+
+        // Insert one more result, which evicts the least recently used result.
+        cache.verify(tip, ids[MAX_VERIFICATION_CACHE_SIZE], || true);
+        assert_eq!(cache.num_verifications(), MAX_VERIFICATION_CACHE_SIZE + 1);
+
+        // Ensure the first ID is still cached, and the second ID was evicted.
+        cache.verify(tip, ids[0], || panic!("The result should be cached"));
+        cache.verify(tip, ids[1], || true);
+        assert_eq!(cache.num_verifications(), MAX_VERIFICATION_CACHE_SIZE + 2);
+    }
+}
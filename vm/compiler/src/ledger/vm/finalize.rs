@@ -30,6 +30,57 @@ impl<N: Network, P: ProgramStorage<N>> VM<N, P> {
         }
     }
 
+    /// Finalizes the given transactions into the VM, in order.
+    /// If any transaction fails to finalize, the process and the program store are restored
+    /// to their state before the first transaction, and the error is returned.
+    /// This method assumes the given transactions **are valid**.
+    pub fn finalize_all<'a>(&mut self, transactions: impl IntoIterator<Item = &'a Transaction<N>>) -> Result<()> {
+        let transactions = transactions.into_iter().collect::<Vec<_>>();
+
+        // Back up the process, and the mappings of each program that the transactions may modify.
+        let process = self.process.read().clone();
+        let program_ids = transactions
+            .iter()
+            .flat_map(|transaction| {
+                let deployed = match transaction {
+                    Transaction::Deploy(_, deployment, _) => Some(*deployment.program_id()),
+                    Transaction::Execute(..) => None,
+                };
+                deployed.into_iter().chain(transaction.transitions().map(|transition| *transition.program_id()))
+            })
+            .collect::<IndexSet<_>>();
+        let mappings = program_ids
+            .into_iter()
+            .map(|program_id| Ok((program_id, self.store.get_mappings(&program_id)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Finalize each transaction.
+        for transaction in transactions {
+            if let Err(error) = self.finalize(transaction) {
+                // Restore the process and the program mappings.
+                *self.process.write() = process;
+                for (program_id, mappings) in mappings {
+                    self.store.restore_mappings(&program_id, mappings)?;
+                }
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    /// Finalizes the transaction into a copy of the VM, and discards the changes.
+    /// This method does not modify the VM, and assumes the given transaction **is valid**.
+    pub fn simulate_finalize(&self, transaction: &Transaction<N>) -> Result<()> {
+        // Initialize a copy of the VM, with a copy of the process and an in-memory copy of the program store.
+        let mut vm = VM::<N, ProgramMemory<N>> {
+            process: Arc::new(RwLock::new(self.process.read().clone())),
+            store: self.store.to_memory()?,
+            _phantom: PhantomData,
+        };
+        // Finalize the transaction into the copy, which is discarded afterwards.
+        vm.finalize(transaction)
+    }
+
     /// Finalizes the deployment in the VM.
     /// This method assumes the given deployment **is valid**.
     #[inline]
@@ -25,7 +25,7 @@ mod verify;
 use crate::{
     cast_ref,
     ledger::{
-        store::{BlockStorage, BlockStore, ProgramStorage, ProgramStore},
+        store::{BlockStorage, BlockStore, ProgramMemory, ProgramStorage, ProgramStore},
         AdditionalFee,
         Transaction,
     },
@@ -40,6 +40,7 @@ use console::{
 };
 
 use core::marker::PhantomData;
+use indexmap::IndexSet;
 use parking_lot::RwLock;
 use std::sync::Arc;
 